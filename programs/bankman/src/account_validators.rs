@@ -17,12 +17,36 @@ macro_rules! assert_is_bankman {
     };
 }
 
+macro_rules! assert_is_risk_manager {
+    ($self: ident) => {
+        assert_keys_eq!(
+            $self.risk_manager,
+            $self.bank.risk_manager,
+            UnauthorizedNotRiskManager
+        );
+    };
+}
+
+macro_rules! assert_is_fee_manager {
+    ($self: ident) => {
+        assert_keys_eq!(
+            $self.fee_manager,
+            $self.bank.fee_manager,
+            UnauthorizedNotFeeManager
+        );
+    };
+}
+
 impl<'info> Validate<'info> for NewBank<'info> {
     fn validate(&self) -> Result<()> {
-        // brrr_issue_authority does not need to be validated because
-        // the Bank is created once
-        // burn_withdraw_authority does not need to be validated because
-        // the Bank is created once
+        assert_keys_eq!(
+            self.brrr_issue_authority,
+            addresses::find_issue_authority_address(&self.bank.key()).0
+        );
+        assert_keys_eq!(
+            self.burn_withdraw_authority,
+            addresses::find_withdraw_authority_address(&self.bank.key()).0
+        );
         require!(self.crate_mint.supply == 0, NewBankSupplyMustBeZero);
         require!(
             self.crate_mint.decimals == CASH_DECIMALS,
@@ -40,6 +64,41 @@ impl<'info> Validate<'info> for AuthorizeCollateral<'info> {
     }
 }
 
+impl<'info> Validate<'info> for SetCollateralReserve<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_risk_manager!(self);
+        assert_keys_eq!(self.collateral.bank, self.bank);
+        require!(
+            self.collateral.kind == CollateralKind::YieldBearing,
+            CollateralKindMismatch
+        );
+        assert_keys_eq!(*self.reserve.owner, self.lending_program, InvalidReserve);
+        let reserve = ReserveState::unpack(&self.reserve.try_borrow_data()?)?;
+        assert_keys_eq!(
+            reserve.collateral_mint,
+            self.collateral.mint,
+            InvalidReserve
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for NewCollateralMetadata<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_curator!(self);
+        assert_keys_eq!(self.collateral.bank, self.bank);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetCollateralMetadata<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_curator!(self);
+        assert_keys_eq!(self.collateral.bank, self.bank);
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for SetCurator<'info> {
     fn validate(&self) -> Result<()> {
         assert_is_bankman!(self);
@@ -54,10 +113,84 @@ impl<'info> Validate<'info> for SetBankman<'info> {
     }
 }
 
+impl<'info> Validate<'info> for SetGovernor<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_bankman!(self);
+
+        assert_keys_eq!(
+            *self.governor.owner,
+            addresses::GOVERN_PROGRAM_ID,
+            GovernorNotTrusted
+        );
+        let smart_wallet = addresses::governor_smart_wallet(&self.governor.try_borrow_data()?);
+        let smart_wallet = unwrap_opt!(smart_wallet, GovernorInvalid);
+        assert_keys_eq!(self.smart_wallet, smart_wallet, GovernorSmartWalletMismatch);
+        Ok(())
+    }
+}
+
 impl<'info> Validate<'info> for SetCollateralHardCap<'info> {
     fn validate(&self) -> Result<()> {
-        assert_is_curator!(self);
+        assert_is_risk_manager!(self);
         assert_keys_eq!(self.collateral.bank, self.bank);
         Ok(())
     }
 }
+
+impl<'info> Validate<'info> for SetPendingTreasury<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_bankman!(self);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyPendingTreasury<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_bankman!(self);
+        require!(self.bank.pending_treasury_eta != 0, NoPendingTreasury);
+        require!(
+            Clock::get()?.unix_timestamp >= self.bank.pending_treasury_eta,
+            TreasuryTimelockNotElapsed
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetCompoundFees<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_fee_manager!(self);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetPendingRevenueSplit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_fee_manager!(self);
+        let (savings_vault, _) = addresses::find_savings_vault_address(&self.bank.key());
+        assert_keys_eq!(
+            self.savings_vault,
+            savings_vault,
+            RevenueSplitInvalidSavingsVault
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for ApplyPendingRevenueSplit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_fee_manager!(self);
+        require!(self.revenue_split.pending_eta != 0, NoPendingRevenueSplit);
+        require!(
+            Clock::get()?.unix_timestamp >= self.revenue_split.pending_eta,
+            RevenueSplitTimelockNotElapsed
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetFlashMintParams<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_risk_manager!(self);
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! Addresses of accounts of other programs which a [crate::Bank] relies on.
+//!
+//! Each [crate::Bank] has its own brrr issue and withdraw authorities, which are PDAs
+//! of the brrr program seeded by the key of the [crate::Bank]. A [crate::Bank] may also
+//! be governed by a Tribeca governor, whose smart wallet is read from its account data,
+//! and has a single savings vault, a PDA of the savings program.
+#![deny(missing_docs)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{self, hash::hash, pubkey::PUBKEY_BYTES};
+
+/// Program ID of brrr, which signs for the authorities.
+pub static BRRR_PROGRAM_ID: Pubkey =
+    static_pubkey::static_pubkey!("BRRRot6ig147TBU6EGp7TMesmQrwu729CbG6qu2ZUHWm");
+
+/// Seed of the issue authority of a [crate::Bank].
+pub const ISSUE_AUTHORITY_SEED: &[u8] = b"print";
+
+/// Seed of the withdraw authority of a [crate::Bank].
+pub const WITHDRAW_AUTHORITY_SEED: &[u8] = b"burn";
+
+/// Finds the issue authority of a [crate::Bank] and its bump.
+pub fn find_issue_authority_address(bank: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[ISSUE_AUTHORITY_SEED, bank.as_ref()], &BRRR_PROGRAM_ID)
+}
+
+/// Finds the withdraw authority of a [crate::Bank] and its bump.
+pub fn find_withdraw_authority_address(bank: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[WITHDRAW_AUTHORITY_SEED, bank.as_ref()], &BRRR_PROGRAM_ID)
+}
+
+/// Program ID of the savings program, whose vault receives the savings portion of a
+/// [crate::RevenueSplit].
+pub static SAVINGS_PROGRAM_ID: Pubkey =
+    static_pubkey::static_pubkey!("EfVMqQBXNY5ctXXtkvaSFGXHEmZVxosTJqDNvbkpbXTP");
+
+/// Seed of the savings vault of a [crate::Bank].
+pub const SAVINGS_VAULT_SEED: &[u8] = b"SavingsVault";
+
+/// Finds the savings vault of a [crate::Bank] and its bump.
+pub fn find_savings_vault_address(bank: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SAVINGS_VAULT_SEED, bank.as_ref()], &SAVINGS_PROGRAM_ID)
+}
+
+/// Program ID of the Tribeca govern program, the only trusted owner of a [crate::Bank::governor].
+pub static GOVERN_PROGRAM_ID: Pubkey =
+    static_pubkey::static_pubkey!("Govz1VyoyLD5BL6CSCxUJLVLsQHRwjfFj1prNsdNg5Jw");
+
+/// Offset of the smart wallet in the data of a Tribeca governor, after its
+/// discriminator, base, bump, proposal count and electorate.
+const GOVERNOR_SMART_WALLET_OFFSET: usize = 8 + PUBKEY_BYTES + 1 + 8 + PUBKEY_BYTES;
+
+/// Reads the smart wallet of a Tribeca governor from its account data.
+/// Returns [None] if the data is not that of a governor.
+pub fn governor_smart_wallet(data: &[u8]) -> Option<Pubkey> {
+    let discriminator = &hash(b"account:Governor").to_bytes()[..8];
+    if data.get(..8)? != discriminator {
+        return None;
+    }
+    let smart_wallet =
+        data.get(GOVERNOR_SMART_WALLET_OFFSET..GOVERNOR_SMART_WALLET_OFFSET + PUBKEY_BYTES)?;
+    Some(Pubkey::new(smart_wallet))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_governor_smart_wallet() {
+        let governor = mock_governor::Governor {
+            base: Pubkey::new_unique(),
+            bump: 254,
+            proposal_count: 3,
+            electorate: Pubkey::new_unique(),
+            smart_wallet: Pubkey::new_unique(),
+            smart_wallet_bump: 253,
+            timelock_delay_seconds: 60,
+        };
+        let mut data = vec![];
+        governor.try_serialize(&mut data).unwrap();
+        assert_eq!(governor_smart_wallet(&data), Some(governor.smart_wallet));
+
+        assert_eq!(governor_smart_wallet(&data[..40]), None);
+        data[0] ^= 1;
+        assert_eq!(governor_smart_wallet(&data), None);
+    }
+
+    #[test]
+    fn test_mock_governor_program_id() {
+        assert_eq!(mock_governor::ID, GOVERN_PROGRAM_ID);
+    }
+}
@@ -29,11 +29,77 @@ pub struct AddCollateralEvent {
     pub curator: Pubkey,
     /// The [anchor_spl::token::Mint] of the collateral.
     pub mint: Pubkey,
+    /// The [crate::CollateralKind] of the collateral.
+    pub kind: crate::CollateralKind,
 
     /// Timestamp of the event.
     pub timestamp: i64,
 }
 
+/// Emitted when the [crate::CollateralMetadata] of a [crate::Collateral] is created.
+#[event]
+pub struct NewCollateralMetadataEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral].
+    #[index]
+    pub collateral: Pubkey,
+    /// The [crate::CollateralMetadata].
+    pub metadata: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [crate::CollateralMetadata] of a [crate::Collateral] is modified.
+#[event]
+pub struct SetCollateralMetadataEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral].
+    #[index]
+    pub collateral: Pubkey,
+    /// The [crate::Bank::curator].
+    pub curator: Pubkey,
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+
+    /// Name of the collateral.
+    pub name: String,
+    /// Mints of the tokens underlying the collateral.
+    pub underlying_mints: Vec<Pubkey>,
+    /// The Saber swap of the collateral's LP.
+    pub saber_swap: Pubkey,
+    /// Risk tier of the collateral.
+    pub risk_tier: u8,
+    /// Time at which the collateral was authorized.
+    pub authorized_at: i64,
+    /// The curator who authorized the collateral.
+    pub authorized_by: Pubkey,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Collateral]'s reserve is modified.
+#[event]
+pub struct SetCollateralReserveEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// Stake pool
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The new [crate::Collateral::reserve].
+    pub reserve: Pubkey,
+    /// The new [crate::Collateral::lending_program].
+    pub lending_program: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
 /// Emitted when an [crate::Bank]'s curator is modified.
 #[event]
 pub struct SetCuratorEvent {
@@ -83,3 +149,490 @@ pub struct SetCollateralHardCapEvent {
     /// Timestamp of the event.
     pub timestamp: i64,
 }
+
+/// Emitted when author fees are withdrawn from a [crate::Bank].
+#[event]
+pub struct WithdrawAuthorFeeEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose fees were withdrawn.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+    /// The token account the fees were sent to.
+    pub destination: Pubkey,
+    /// Amount of collateral tokens withdrawn.
+    pub amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a new [crate::Bank] treasury is proposed.
+#[event]
+pub struct SetPendingTreasuryEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::Bank::pending_treasury].
+    pub pending_treasury: Pubkey,
+    /// The current [crate::Bank::treasury].
+    pub treasury: Pubkey,
+    /// Time at which the pending treasury may be applied.
+    pub eta: i64,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RevenueSplit] is proposed.
+#[event]
+pub struct SetPendingRevenueSplitEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::RevenueSplit::pending_treasury_bps].
+    pub treasury_bps: u16,
+    /// The new [crate::RevenueSplit::pending_insurance_fund_bps].
+    pub insurance_fund_bps: u16,
+    /// The new [crate::RevenueSplit::pending_savings_bps].
+    pub savings_bps: u16,
+    /// The new [crate::RevenueSplit::pending_stakers_bps].
+    pub stakers_bps: u16,
+    /// The new [crate::RevenueSplit::pending_savings_vault].
+    pub savings_vault: Pubkey,
+    /// The new [crate::RevenueSplit::pending_stakers].
+    pub stakers: Pubkey,
+    /// Time at which the pending split may be applied.
+    pub eta: i64,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when an [crate::Bank]'s treasury is modified.
+#[event]
+pub struct SetTreasuryEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::Bank::treasury].
+    pub treasury: Pubkey,
+    /// The previous [crate::Bank::treasury].
+    pub previous_treasury: Pubkey,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when an [crate::Bank]'s fee compounding setting is modified.
+#[event]
+pub struct SetCompoundFeesEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::Bank::compound_fees].
+    pub compound_fees: bool,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when author fees are deposited back into the backing of $CASH.
+#[event]
+pub struct CompoundFeesEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose fees were compounded.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+    /// Amount of collateral tokens added to the backing.
+    pub collateral_amount: u64,
+    /// Value added to the backing, in $CASH, at the nominal price of the collateral.
+    pub cash_value: u64,
+    /// Collateral tokens backing $CASH after the deposit.
+    pub backing_collateral_amount: u64,
+    /// The [crate::Bank::surplus] after the deposit.
+    pub surplus: u64,
+    /// The [crate::Bank::bad_debt] after the deposit.
+    pub bad_debt: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the value of a [crate::Collateral] is written down.
+#[event]
+pub struct WriteDownCollateralEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] written down.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+    /// Value written down, in $CASH.
+    pub amount: u64,
+    /// The [crate::Bank::surplus] after the write-down.
+    pub surplus: u64,
+    /// The [crate::Bank::bad_debt] after the write-down.
+    pub bad_debt: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when [crate::Bank::bad_debt] is covered from the surplus buffer.
+#[event]
+pub struct CoverBadDebtEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// Surplus buffer the $CASH was burned from.
+    pub surplus_buffer: Pubkey,
+    /// Amount of $CASH burned.
+    pub amount: u64,
+    /// The remaining [crate::Bank::bad_debt].
+    pub bad_debt: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when an [crate::InsuranceFund] is created.
+#[event]
+pub struct NewInsuranceFundEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The [crate::InsuranceFund].
+    pub insurance_fund: Pubkey,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [crate::InsuranceFund] covers a shortfall in the backing of a [crate::Collateral].
+#[event]
+pub struct CoverShortfallEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose backing was topped up.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [crate::InsuranceFund].
+    pub insurance_fund: Pubkey,
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+    /// Amount of collateral tokens injected into the backing.
+    pub amount: u64,
+    /// Collateral tokens backing $CASH after the injection.
+    pub backing_amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when an [crate::Bank]'s flash mint parameters are modified.
+#[event]
+pub struct SetFlashMintParamsEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::Bank::flash_mint_ceiling].
+    pub flash_mint_ceiling: u64,
+    /// The new [crate::Bank::flash_mint_fee_bps].
+    pub flash_mint_fee_bps: u16,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the farming rewards of a [crate::Collateral] are harvested to the [crate::Bank].
+#[event]
+pub struct HarvestRewardsEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose Arrow was harvested.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the rewards.
+    pub rewards_mint: Pubkey,
+    /// Amount of rewards received by the [crate::Bank], after Arrow and Sunny fees.
+    pub amount: u64,
+    /// Rewards tokens held by the [crate::Bank] after the harvest.
+    pub rewards_balance: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when harvested rewards are swapped into the LP of a [crate::Collateral].
+#[event]
+pub struct SwapRewardsToBackingEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose Arrow received the LP tokens.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the rewards.
+    pub rewards_mint: Pubkey,
+    /// Amount of rewards swapped.
+    pub rewards_amount: u64,
+    /// Amount of stablecoin received from the swap.
+    pub stablecoin_amount: u64,
+    /// Amount of LP tokens minted and staked.
+    pub lp_amount: u64,
+    /// Whether the Arrow tokens were added to the backing of $CASH,
+    /// rather than kept by the Bank.
+    pub to_backing: bool,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RevenueSplit] is created.
+#[event]
+pub struct NewRevenueSplitEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::RevenueSplit].
+    pub revenue_split: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RevenueSplit] is modified.
+#[event]
+pub struct SetRevenueSplitEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+
+    /// The new [crate::RevenueSplit::treasury_bps].
+    pub treasury_bps: u16,
+    /// The new [crate::RevenueSplit::insurance_fund_bps].
+    pub insurance_fund_bps: u16,
+    /// The new [crate::RevenueSplit::savings_bps].
+    pub savings_bps: u16,
+    /// The new [crate::RevenueSplit::stakers_bps].
+    pub stakers_bps: u16,
+    /// The new [crate::RevenueSplit::savings_vault].
+    pub savings_vault: Pubkey,
+    /// The new [crate::RevenueSplit::stakers].
+    pub stakers: Pubkey,
+
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the author fees of a [crate::Collateral] are distributed.
+#[event]
+pub struct DistributeFeesEvent {
+    /// crate::Bank
+    #[index]
+    pub bank: Pubkey,
+    /// The [crate::Collateral] whose fees were distributed.
+    #[index]
+    pub collateral: Pubkey,
+
+    /// The [anchor_spl::token::Mint] of the collateral.
+    pub mint: Pubkey,
+    /// Total amount of fees distributed.
+    pub amount: u64,
+    /// Amount sent to the treasury.
+    pub treasury_amount: u64,
+    /// Amount sent to the insurance fund.
+    pub insurance_fund_amount: u64,
+    /// Amount sent to the savings vault.
+    pub savings_amount: u64,
+    /// Amount sent to the stakers.
+    pub stakers_amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::SignerSet] is created.
+#[event]
+pub struct NewSignerSetEvent {
+    /// The [crate::SignerSet].
+    #[index]
+    pub signer_set: Pubkey,
+    /// Number of approvals required to execute a proposal.
+    pub threshold: u64,
+    /// Owners of the set.
+    pub owners: Vec<Pubkey>,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the owners of a [crate::SignerSet] are modified.
+#[event]
+pub struct SetSignerSetOwnersEvent {
+    /// The [crate::SignerSet].
+    #[index]
+    pub signer_set: Pubkey,
+    /// Number of approvals required to execute a proposal.
+    pub threshold: u64,
+    /// Owners of the set.
+    pub owners: Vec<Pubkey>,
+    /// The new [crate::SignerSet::owner_set_seqno].
+    pub owner_set_seqno: u32,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Proposal] is created.
+#[event]
+pub struct CreateProposalEvent {
+    /// The [crate::SignerSet].
+    #[index]
+    pub signer_set: Pubkey,
+    /// The [crate::Proposal].
+    #[index]
+    pub proposal: Pubkey,
+    /// Index of the proposal.
+    pub index: u64,
+    /// Owner who created the proposal.
+    pub proposer: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Proposal] is approved.
+#[event]
+pub struct ApproveProposalEvent {
+    /// The [crate::SignerSet].
+    #[index]
+    pub signer_set: Pubkey,
+    /// The [crate::Proposal].
+    #[index]
+    pub proposal: Pubkey,
+    /// Owner who approved the proposal.
+    pub owner: Pubkey,
+    /// Number of approvals of the proposal.
+    pub num_approvals: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Proposal] is executed.
+#[event]
+pub struct ExecuteProposalEvent {
+    /// The [crate::SignerSet].
+    #[index]
+    pub signer_set: Pubkey,
+    /// The [crate::Proposal].
+    #[index]
+    pub proposal: Pubkey,
+    /// Executor of the proposal.
+    pub executor: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when the [crate::Bank::bankman] is handed to the smart wallet of a governor.
+#[event]
+pub struct SetGovernorEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The governor.
+    pub governor: Pubkey,
+    /// The program which owns the governor.
+    pub governor_program: Pubkey,
+    /// The previous [crate::Bank::bankman].
+    pub previous_bankman: Pubkey,
+    /// The smart wallet of the governor, which is the new [crate::Bank::bankman].
+    pub bankman: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a new holder of a [crate::BankRole] is appointed.
+#[event]
+pub struct SetPendingRoleEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The role.
+    pub role: crate::BankRole,
+    /// The pending holder of the role.
+    pub pending: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::BankRole] is accepted.
+#[event]
+pub struct AcceptRoleEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The role.
+    pub role: crate::BankRole,
+    /// The previous holder of the role.
+    pub previous: Pubkey,
+    /// The new holder of the role.
+    pub next: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Bank] is paused or unpaused.
+#[event]
+pub struct SetPausedEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// The guardian or bankman who paused or unpaused the [crate::Bank].
+    pub authority: Pubkey,
+    /// Whether the [crate::Bank] is now paused.
+    pub paused: bool,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Bank] is migrated to a new layout version.
+#[event]
+pub struct MigrateBankEvent {
+    /// The [crate::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// Version before the migration.
+    pub previous_version: u8,
+    /// Version after the migration.
+    pub version: u8,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Collateral] is migrated to a new layout version.
+#[event]
+pub struct MigrateCollateralEvent {
+    /// The [crate::Collateral].
+    #[index]
+    pub collateral: Pubkey,
+    /// Version before the migration.
+    pub previous_version: u8,
+    /// Version after the migration.
+    pub version: u8,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
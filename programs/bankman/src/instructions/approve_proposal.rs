@@ -0,0 +1,47 @@
+use crate::*;
+
+/// Accounts for [bankman::approve_proposal].
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// The [SignerSet].
+    pub signer_set: Account<'info, SignerSet>,
+    /// The [Proposal].
+    #[account(mut, has_one = signer_set)]
+    pub proposal: Account<'info, Proposal>,
+    /// An owner of the [SignerSet].
+    pub owner: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for ApproveProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.signer_set.owner_index(self.owner.key()).is_some(),
+            NotSignerSetOwner
+        );
+        invariant!(
+            self.proposal.owner_set_seqno == self.signer_set.owner_set_seqno,
+            ProposalOwnerSetChanged
+        );
+        invariant!(self.proposal.executed_at == 0, ProposalAlreadyExecuted);
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<ApproveProposal>) -> Result<()> {
+    let owner_index = unwrap_opt!(ctx
+        .accounts
+        .signer_set
+        .owner_index(ctx.accounts.owner.key()));
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.approvals[owner_index] = true;
+
+    emit!(ApproveProposalEvent {
+        signer_set: proposal.signer_set,
+        proposal: proposal.key(),
+        owner: ctx.accounts.owner.key(),
+        num_approvals: proposal.num_approvals(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
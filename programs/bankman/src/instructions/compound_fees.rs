@@ -0,0 +1,84 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [bankman::compound_fees].
+#[derive(Accounts)]
+pub struct CompoundFees<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Collateral].
+    #[account(has_one = bank)]
+    pub collateral: Account<'info, Collateral>,
+    /// [Mint] of the [Collateral].
+    pub collateral_mint: Account<'info, Mint>,
+    /// Author fees.
+    #[account(mut)]
+    pub author_fees: Account<'info, TokenAccount>,
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_collateral_tokens: Account<'info, TokenAccount>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for CompoundFees<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(self.bank.compound_fees, CompoundFeesDisabled);
+        assert_keys_eq!(self.author_fees.owner, self.bank);
+        assert_keys_eq!(self.author_fees.mint, self.collateral.mint);
+        assert_keys_eq!(self.crate_collateral_tokens.owner, self.bank.crate_token);
+        assert_keys_eq!(self.crate_collateral_tokens.mint, self.collateral.mint);
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, CompoundFees<'info>>) -> Result<()> {
+    let amount = ctx.accounts.author_fees.amount;
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let cash_value = pricing::cash_value(
+        &ctx.accounts.collateral,
+        &ctx.accounts.collateral_mint,
+        amount,
+        ctx.remaining_accounts,
+    )?;
+
+    let bank = &mut ctx.accounts.bank;
+    bank.surplus = unwrap_int!(bank.surplus.checked_add(cash_value));
+    bank.cover_bad_debt_from_surplus();
+
+    let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(bank)];
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.author_fees.to_account_info(),
+                to: ctx.accounts.crate_collateral_tokens.to_account_info(),
+                authority: bank.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+    )?;
+
+    let backing_collateral_amount = unwrap_int!(ctx
+        .accounts
+        .crate_collateral_tokens
+        .amount
+        .checked_add(amount));
+    emit!(CompoundFeesEvent {
+        bank: bank.key(),
+        collateral: ctx.accounts.collateral.key(),
+        mint: ctx.accounts.collateral.mint,
+        collateral_amount: amount,
+        cash_value,
+        backing_collateral_amount,
+        surplus: bank.surplus,
+        bad_debt: bank.bad_debt,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [bankman::cover_bad_debt].
+#[derive(Accounts)]
+pub struct CoverBadDebt<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Account<'info, Mint>,
+    /// Surplus buffer: a $CASH token account owned by the [Bank].
+    #[account(mut)]
+    pub surplus_buffer: Account<'info, TokenAccount>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for CoverBadDebt<'info> {
+    fn validate(&self) -> Result<()> {
+        validate_surplus_buffer(&self.bank, &self.crate_mint, &self.surplus_buffer)
+    }
+}
+
+pub fn handler(ctx: Context<CoverBadDebt>) -> Result<()> {
+    let accounts = ctx.accounts;
+    burn_from_surplus_buffer(
+        &mut accounts.bank,
+        &accounts.crate_mint,
+        &accounts.surplus_buffer,
+        &accounts.token_program,
+    )
+}
+
+/// Checks that the surplus buffer is a $CASH account owned by the [Bank].
+pub(crate) fn validate_surplus_buffer(
+    bank: &Account<Bank>,
+    crate_mint: &Account<Mint>,
+    surplus_buffer: &Account<TokenAccount>,
+) -> Result<()> {
+    assert_keys_eq!(bank.crate_mint, crate_mint.key());
+    assert_keys_eq!(surplus_buffer.owner, bank.key());
+    assert_keys_eq!(surplus_buffer.mint, bank.crate_mint);
+    Ok(())
+}
+
+/// Burns as much of the [Bank::bad_debt] as the surplus buffer can cover.
+pub(crate) fn burn_from_surplus_buffer<'info>(
+    bank: &mut Account<'info, Bank>,
+    crate_mint: &Account<'info, Mint>,
+    surplus_buffer: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+) -> Result<()> {
+    let amount = bank.bad_debt.min(surplus_buffer.amount);
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(bank)];
+    token::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::Burn {
+                mint: crate_mint.to_account_info(),
+                from: surplus_buffer.to_account_info(),
+                authority: bank.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+    )?;
+    bank.bad_debt = unwrap_int!(bank.bad_debt.checked_sub(amount));
+
+    emit!(CoverBadDebtEvent {
+        bank: bank.key(),
+        surplus_buffer: surplus_buffer.key(),
+        amount,
+        bad_debt: bank.bad_debt,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+    Ok(())
+}
@@ -0,0 +1,83 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Accounts for [bankman::cover_shortfall].
+#[derive(Accounts)]
+pub struct CoverShortfall<'info> {
+    /// The [Bank].
+    #[account(has_one = bankman @ crate::ErrorCode::UnauthorizedNotBankman)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman], which must be the smart wallet of the [Bank::governor]
+    /// or a [SignerSet].
+    pub bankman: Signer<'info>,
+    /// The [InsuranceFund].
+    #[account(has_one = bank)]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+    /// The [Collateral] whose backing is topped up.
+    #[account(has_one = bank)]
+    pub collateral: Account<'info, Collateral>,
+    /// Insurance fund tokens of the [Collateral].
+    #[account(mut)]
+    pub insurance_fund_tokens: Account<'info, TokenAccount>,
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_collateral_tokens: Account<'info, TokenAccount>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for CoverShortfall<'info> {
+    fn validate(&self) -> Result<()> {
+        // insurance funds may only be injected through a governance proposal
+        let bankman = self.bankman.to_account_info();
+        require!(
+            self.bank.governor != Pubkey::default()
+                || Account::<SignerSet>::try_from(&bankman).is_ok(),
+            CoverShortfallNotGoverned
+        );
+        assert_keys_eq!(self.insurance_fund_tokens.owner, self.insurance_fund);
+        assert_keys_eq!(self.insurance_fund_tokens.mint, self.collateral.mint);
+        assert_keys_eq!(self.crate_collateral_tokens.owner, self.bank.crate_token);
+        assert_keys_eq!(self.crate_collateral_tokens.mint, self.collateral.mint);
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<CoverShortfall>, amount: u64) -> Result<()> {
+    let bank = &ctx.accounts.bank;
+    let insurance_fund = &ctx.accounts.insurance_fund;
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        b"InsuranceFund".as_ref(),
+        insurance_fund.bank.as_ref(),
+        &[insurance_fund.bump],
+    ]];
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.insurance_fund_tokens.to_account_info(),
+                to: ctx.accounts.crate_collateral_tokens.to_account_info(),
+                authority: insurance_fund.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+    )?;
+
+    let backing_amount = unwrap_int!(ctx
+        .accounts
+        .crate_collateral_tokens
+        .amount
+        .checked_add(amount));
+    emit!(CoverShortfallEvent {
+        bank: bank.key(),
+        collateral: ctx.accounts.collateral.key(),
+        insurance_fund: insurance_fund.key(),
+        mint: ctx.accounts.collateral.mint,
+        amount,
+        backing_amount,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
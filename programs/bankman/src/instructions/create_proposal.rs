@@ -0,0 +1,157 @@
+use crate::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Instructions which a [SignerSet] may sign via a [Proposal].
+pub const PROPOSAL_ALLOWED_INSTRUCTIONS: &[&str] = &[
+    "set_curator",
+    "set_bankman",
+    "set_governor",
+    "authorize_collateral",
+    "set_collateral_hard_cap",
+    "set_collateral_reserve",
+    "new_collateral_metadata",
+    "set_collateral_metadata",
+    "withdraw_author_fee",
+    "set_signer_set_owners",
+    "set_risk_manager",
+    "accept_risk_manager",
+    "set_fee_manager",
+    "accept_fee_manager",
+    "set_guardian",
+    "accept_guardian",
+    "pause",
+    "unpause",
+    "set_pending_treasury",
+    "apply_pending_treasury",
+    "set_compound_fees",
+    "write_down_collateral",
+    "new_insurance_fund",
+    "cover_shortfall",
+    "new_revenue_split",
+    "set_pending_revenue_split",
+    "apply_pending_revenue_split",
+    "set_flash_mint_params",
+    "swap_rewards_to_backing",
+];
+
+/// Accounts for [bankman::create_proposal].
+#[derive(Accounts)]
+#[instruction(instruction: ProposalInstruction)]
+pub struct CreateProposal<'info> {
+    /// The [SignerSet].
+    #[account(mut)]
+    pub signer_set: Account<'info, SignerSet>,
+
+    /// The [Proposal] to create.
+    #[account(
+        init,
+        seeds = [
+            b"Proposal".as_ref(),
+            signer_set.key().to_bytes().as_ref(),
+            signer_set.num_proposals.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + Proposal::space(&instruction, signer_set.owners.len()),
+        payer = payer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// The proposer, who must be an owner of the [SignerSet].
+    pub proposer: Signer<'info>,
+
+    /// Payer of the proposal initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for CreateProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.signer_set.owner_index(self.proposer.key()).is_some(),
+            NotSignerSetOwner
+        );
+        Ok(())
+    }
+}
+
+/// Computes the Anchor instruction discriminator of the [bankman](crate::bankman) instruction `name`.
+fn sighash(name: &str) -> [u8; 8] {
+    let mut sighash = [0_u8; 8];
+    sighash.copy_from_slice(&hash(format!("global:{}", name).as_bytes()).to_bytes()[..8]);
+    sighash
+}
+
+/// Checks that the instruction is one of the [PROPOSAL_ALLOWED_INSTRUCTIONS].
+pub(crate) fn validate_proposal_instruction(instruction: &ProposalInstruction) -> Result<()> {
+    invariant!(
+        instruction.data.len() >= 8
+            && PROPOSAL_ALLOWED_INSTRUCTIONS
+                .iter()
+                .any(|name| instruction.data[..8] == sighash(name)),
+        ProposalInstructionNotAllowed
+    );
+    Ok(())
+}
+
+pub fn handler(ctx: Context<CreateProposal>, instruction: ProposalInstruction) -> Result<()> {
+    validate_proposal_instruction(&instruction)?;
+
+    let signer_set = &mut ctx.accounts.signer_set;
+    let proposer_index = unwrap_opt!(signer_set.owner_index(ctx.accounts.proposer.key()));
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.signer_set = signer_set.key();
+    proposal.index = signer_set.num_proposals;
+    proposal.bump = unwrap_bump!(ctx, "proposal");
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.owner_set_seqno = signer_set.owner_set_seqno;
+    proposal.instruction = instruction;
+    // the proposer approves their own proposal
+    proposal.approvals = vec![false; signer_set.owners.len()];
+    proposal.approvals[proposer_index] = true;
+
+    signer_set.num_proposals = unwrap_int!(signer_set.num_proposals.checked_add(1));
+
+    emit!(CreateProposalEvent {
+        signer_set: signer_set.key(),
+        proposal: proposal.key(),
+        index: proposal.index,
+        proposer: proposal.proposer,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sighash_matches_anchor() {
+        use anchor_lang::InstructionData;
+        assert_eq!(
+            crate::instruction::SetCurator {}.data(),
+            sighash("set_curator").to_vec()
+        );
+    }
+
+    #[test]
+    fn test_allows_timelocked_instructions() {
+        use anchor_lang::InstructionData;
+        for data in [
+            crate::instruction::ApplyPendingTreasury {}.data(),
+            crate::instruction::ApplyPendingRevenueSplit {}.data(),
+            crate::instruction::Unpause {}.data(),
+        ] {
+            let instruction = ProposalInstruction {
+                accounts: vec![],
+                data,
+            };
+            assert!(validate_proposal_instruction(&instruction).is_ok());
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::*;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+/// Number of remaining accounts per [Collateral] in [bankman::distribute_fees].
+pub const DISTRIBUTE_FEES_ACCOUNTS_PER_COLLATERAL: usize = 6;
+
+/// Accounts for [bankman::distribute_fees].
+///
+/// The remaining accounts are groups of
+/// `[collateral, author_fees, treasury_tokens, insurance_fund_tokens, savings_tokens, stakers_tokens]`,
+/// one group per [Collateral] to distribute.
+///
+/// A recipient whose portion of the [RevenueSplit] is zero is neither read nor written,
+/// so any account, such as its `author_fees`, may be passed in its place.
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [RevenueSplit] of the [Bank].
+    #[account(has_one = bank)]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for DistributeFees<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A recipient of a portion of the fees.
+struct Recipient<'a, 'info> {
+    tokens: &'a AccountInfo<'info>,
+    owner: Pubkey,
+    bps: u16,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    invariant!(
+        !remaining.is_empty() && remaining.len() % DISTRIBUTE_FEES_ACCOUNTS_PER_COLLATERAL == 0,
+        DistributeFeesInvalidAccounts
+    );
+
+    let bank = &ctx.accounts.bank;
+    let split = &ctx.accounts.revenue_split;
+    for group in remaining.chunks_exact(DISTRIBUTE_FEES_ACCOUNTS_PER_COLLATERAL) {
+        let collateral: Account<Collateral> = Account::try_from(&group[0])?;
+        let author_fees: Account<TokenAccount> = Account::try_from(&group[1])?;
+        invariant!(group[1].is_writable);
+        assert_keys_eq!(collateral.bank, bank.key());
+        assert_keys_eq!(author_fees.owner, bank.key());
+        assert_keys_eq!(author_fees.mint, collateral.mint);
+
+        // the treasury is last so that it receives the rounding remainder
+        let recipients = [
+            (&group[3], bank.insurance_fund, split.insurance_fund_bps),
+            (&group[4], split.savings_vault, split.savings_bps),
+            (&group[5], split.stakers, split.stakers_bps),
+            (&group[2], bank.treasury, split.treasury_bps),
+        ]
+        .map(|(tokens, owner, bps)| Recipient { tokens, owner, bps });
+
+        let amount = author_fees.amount;
+        if amount == 0 {
+            continue;
+        }
+
+        let mut amounts = [0_u64; 4];
+        let mut distributed: u64 = 0;
+        for (i, recipient) in recipients.iter().enumerate() {
+            // only load and validate recipients which receive fees
+            if recipient.bps == 0 {
+                continue;
+            }
+            let tokens: Account<TokenAccount> = Account::try_from(recipient.tokens)?;
+            invariant!(recipient.tokens.is_writable);
+            assert_keys_eq!(tokens.owner, recipient.owner);
+            assert_keys_eq!(tokens.mint, collateral.mint);
+            assert_keys_neq!(tokens, author_fees);
+
+            amounts[i] = if i == recipients.len() - 1 {
+                unwrap_int!(amount.checked_sub(distributed))
+            } else {
+                unwrap_int!(apply_bps(amount, recipient.bps))
+            };
+            distributed = unwrap_int!(distributed.checked_add(amounts[i]));
+            transfer_fees(
+                bank,
+                &author_fees,
+                &tokens,
+                &ctx.accounts.token_program,
+                amounts[i],
+            )?;
+        }
+
+        let [insurance_fund_amount, savings_amount, stakers_amount, treasury_amount] = amounts;
+        emit!(DistributeFeesEvent {
+            bank: bank.key(),
+            collateral: collateral.key(),
+            mint: collateral.mint,
+            amount: distributed,
+            treasury_amount,
+            insurance_fund_amount,
+            savings_amount,
+            stakers_amount,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+    }
+
+    Ok(())
+}
+
+fn transfer_fees<'info>(
+    bank: &Account<'info, Bank>,
+    author_fees: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(bank)];
+    token::transfer(
+        CpiContext::new(
+            token_program.to_account_info(),
+            token::Transfer {
+                from: author_fees.to_account_info(),
+                to: destination.to_account_info(),
+                authority: bank.to_account_info(),
+            },
+        )
+        .with_signer(signer_seeds),
+        amount,
+    )
+}
@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+/// Accounts for [bankman::execute_proposal].
+///
+/// The remaining accounts are the accounts of the [ProposalInstruction],
+/// followed by the [bankman](crate::bankman) program.
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// The [SignerSet].
+    /// This is not writable, so that the executed instruction may modify it.
+    pub signer_set: Account<'info, SignerSet>,
+    /// The [Proposal].
+    #[account(mut, has_one = signer_set)]
+    pub proposal: Account<'info, Proposal>,
+    /// The executor. Anyone may execute an approved proposal.
+    pub executor: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for ExecuteProposal<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.proposal.owner_set_seqno == self.signer_set.owner_set_seqno,
+            ProposalOwnerSetChanged
+        );
+        invariant!(self.proposal.executed_at == 0, ProposalAlreadyExecuted);
+        invariant!(
+            self.proposal.num_approvals() >= self.signer_set.threshold,
+            ProposalThresholdNotMet
+        );
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    validate_proposal_instruction(&proposal.instruction)?;
+
+    let instruction = Instruction {
+        program_id: crate::ID,
+        accounts: proposal
+            .instruction
+            .accounts
+            .iter()
+            .map(|meta| AccountMeta {
+                pubkey: meta.pubkey,
+                is_signer: meta.is_signer,
+                is_writable: meta.is_writable,
+            })
+            .collect(),
+        data: proposal.instruction.data.clone(),
+    };
+    let signer_set = &ctx.accounts.signer_set;
+    invoke_signed(
+        &instruction,
+        ctx.remaining_accounts,
+        &[gen_signer_set_signer_seeds!(signer_set)],
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.executed_at = now;
+
+    emit!(ExecuteProposalEvent {
+        signer_set: proposal.signer_set,
+        proposal: proposal.key(),
+        executor: ctx.accounts.executor.key(),
+        timestamp: now
+    });
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use crate::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use arrow_sunny::Arrow;
+
+/// Accounts for [bankman::harvest_rewards].
+#[derive(Accounts)]
+pub struct HarvestRewards<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Collateral], whose mint is the [Arrow]'s mint.
+    #[account(has_one = bank)]
+    pub collateral: Account<'info, Collateral>,
+    /// The [Arrow] of the [Collateral]. Its beneficiary must be the [Bank].
+    pub arrow: Box<Account<'info, Arrow>>,
+
+    /// [TokenAccount] of the [Bank] receiving the rewards.
+    #[account(mut)]
+    pub bank_rewards_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Accounts for claiming the rewards into the Sunny vault.
+    pub claim: HarvestClaimAccounts<'info>,
+    /// Accounts for withdrawing the claimed rewards from the Sunny vault.
+    pub withdraw: HarvestWithdrawAccounts<'info>,
+
+    /// Sunny pool.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+    /// Sunny vault.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Arrow program.
+    pub arrow_program: Program<'info, arrow_sunny::program::ArrowSunny>,
+    /// Sunny program.
+    /// CHECK: checked by Arrow.
+    pub sunny_program: UncheckedAccount<'info>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for [arrow_sunny::arrow_sunny::claim].
+#[derive(Accounts)]
+pub struct HarvestClaimAccounts<'info> {
+    /// Vault account holding the claimed rewards.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub vault_rewards_token_account: UncheckedAccount<'info>,
+    /// Quarry claim fee account.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub claim_fee_token_account: UncheckedAccount<'info>,
+    /// Dummy staked token account.
+    /// CHECK: checked by Sunny.
+    #[account(mut)]
+    pub stake_token_account: UncheckedAccount<'info>,
+
+    /// Quarry rewarder.
+    /// CHECK: checked by Arrow.
+    pub rewarder: UncheckedAccount<'info>,
+    /// Quarry.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub quarry: UncheckedAccount<'info>,
+    /// Quarry miner.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub miner: UncheckedAccount<'info>,
+    /// Quarry miner vault.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub miner_vault: UncheckedAccount<'info>,
+
+    /// Quarry mint wrapper.
+    /// CHECK: checked by Sunny.
+    #[account(mut)]
+    pub mint_wrapper: UncheckedAccount<'info>,
+    /// Quarry minter.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub minter: UncheckedAccount<'info>,
+    /// [Mint] of the rewards token.
+    #[account(mut)]
+    pub rewards_token_mint: Box<Account<'info, Mint>>,
+
+    /// Quarry mine program.
+    /// CHECK: checked by Arrow.
+    pub mine_program: UncheckedAccount<'info>,
+    /// Quarry mint wrapper program.
+    /// CHECK: checked by Arrow.
+    pub mint_wrapper_program: UncheckedAccount<'info>,
+    /// Clock sysvar.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Accounts for [arrow_sunny::arrow_sunny::withdraw_rewards_to_beneficiary].
+#[derive(Accounts)]
+pub struct HarvestWithdrawAccounts<'info> {
+    /// Arrow protocol fee account.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub arrow_fee_account: UncheckedAccount<'info>,
+    /// Sunny pool fee account.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub sunny_pool_fee_account: UncheckedAccount<'info>,
+    /// Arrow staging account.
+    /// CHECK: checked by Arrow.
+    #[account(mut)]
+    pub arrow_staging_account: UncheckedAccount<'info>,
+}
+
+impl<'info> Validate<'info> for HarvestRewards<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.collateral.kind == CollateralKind::SaberLpArrow,
+            CollateralKindMismatch
+        );
+        assert_keys_eq!(self.arrow.mint, self.collateral.mint);
+        assert_keys_eq!(
+            self.arrow.beneficiary,
+            self.bank,
+            HarvestRewardsNotBeneficiary
+        );
+
+        assert_keys_eq!(self.bank_rewards_tokens.owner, self.bank);
+        assert_keys_eq!(self.bank_rewards_tokens.mint, self.claim.rewards_token_mint);
+        Ok(())
+    }
+}
+
+impl<'info> HarvestRewards<'info> {
+    fn claim(&self) -> Result<()> {
+        let claim = &self.claim;
+        arrow_sunny::cpi::claim(CpiContext::new(
+            self.arrow_program.to_account_info(),
+            arrow_sunny::cpi::accounts::Claim {
+                arrow: self.arrow.to_account_info(),
+                vault_rewards_token_account: claim.vault_rewards_token_account.to_account_info(),
+                claim_fee_token_account: claim.claim_fee_token_account.to_account_info(),
+                stake_token_account: claim.stake_token_account.to_account_info(),
+                stake: arrow_sunny::cpi::accounts::StakeCommon {
+                    rewarder: claim.rewarder.to_account_info(),
+                    quarry: claim.quarry.to_account_info(),
+                    miner: claim.miner.to_account_info(),
+                    miner_vault: claim.miner_vault.to_account_info(),
+                },
+                mint_wrapper: claim.mint_wrapper.to_account_info(),
+                minter: claim.minter.to_account_info(),
+                rewards_token_mint: claim.rewards_token_mint.to_account_info(),
+                pool: self.pool.to_account_info(),
+                vault: self.vault.to_account_info(),
+                mine_program: claim.mine_program.to_account_info(),
+                mint_wrapper_program: claim.mint_wrapper_program.to_account_info(),
+                sunny_program: self.sunny_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                clock: claim.clock.to_account_info(),
+            },
+        ))
+    }
+
+    fn withdraw_to_bank(&self) -> Result<()> {
+        let withdraw = &self.withdraw;
+        arrow_sunny::cpi::withdraw_rewards_to_beneficiary(CpiContext::new(
+            self.arrow_program.to_account_info(),
+            arrow_sunny::cpi::accounts::WithdrawRewardsToBeneficiary {
+                arrow: self.arrow.to_account_info(),
+                beneficiary_account: self.bank_rewards_tokens.to_account_info(),
+                arrow_fee_account: withdraw.arrow_fee_account.to_account_info(),
+                sunny_pool_fee_account: withdraw.sunny_pool_fee_account.to_account_info(),
+                arrow_staging_account: withdraw.arrow_staging_account.to_account_info(),
+                vault_rewards_token_account: self
+                    .claim
+                    .vault_rewards_token_account
+                    .to_account_info(),
+                pool: self.pool.to_account_info(),
+                vault: self.vault.to_account_info(),
+                sunny_program: self.sunny_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))
+    }
+}
+
+pub fn handler(ctx: Context<HarvestRewards>) -> Result<()> {
+    let balance_before = ctx.accounts.bank_rewards_tokens.amount;
+    ctx.accounts.claim()?;
+    ctx.accounts.withdraw_to_bank()?;
+
+    ctx.accounts.bank_rewards_tokens.reload()?;
+    let rewards_balance = ctx.accounts.bank_rewards_tokens.amount;
+    let amount = unwrap_int!(rewards_balance.checked_sub(balance_before));
+
+    emit!(HarvestRewardsEvent {
+        bank: ctx.accounts.bank.key(),
+        collateral: ctx.accounts.collateral.key(),
+        rewards_mint: ctx.accounts.claim.rewards_token_mint.key(),
+        amount,
+        rewards_balance,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::*;
+use anchor_lang::{system_program, Discriminator};
+
+/// Accounts for [bankman::migrate_bank].
+#[derive(Accounts)]
+pub struct MigrateBank<'info> {
+    /// The [Bank], which may be in a previous layout.
+    /// CHECK: Checked to be a [Bank] by its discriminator.
+    #[account(mut, owner = crate::ID)]
+    pub bank: UncheckedAccount<'info>,
+    /// Payer of the rent of the added space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [bankman::migrate_collateral].
+#[derive(Accounts)]
+pub struct MigrateCollateral<'info> {
+    /// The [Collateral], which may be in a previous layout.
+    /// CHECK: Checked to be a [Collateral] by its discriminator.
+    #[account(mut, owner = crate::ID)]
+    pub collateral: UncheckedAccount<'info>,
+    /// Payer of the rent of the added space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for MigrateBank<'info> {
+    fn validate(&self) -> Result<()> {
+        validate_migration::<Bank>(&self.bank, Bank::BYTES)
+    }
+}
+
+impl<'info> Validate<'info> for MigrateCollateral<'info> {
+    fn validate(&self) -> Result<()> {
+        validate_migration::<Collateral>(&self.collateral, Collateral::BYTES)
+    }
+}
+
+/// Checks that the account is of type `T` and is no larger than its current layout.
+fn validate_migration<T: Discriminator>(account: &UncheckedAccount, bytes: usize) -> Result<()> {
+    let data = account.try_borrow_data()?;
+    invariant!(
+        data.len() >= 8 && data[..8] == T::discriminator(),
+        MigrateInvalidAccount
+    );
+    invariant!(data.len() <= 8 + bytes, MigrateInvalidAccount);
+    Ok(())
+}
+
+/// Grows the account to `8 + bytes` bytes, topping up its rent from the payer.
+/// The added space is zeroed.
+fn realloc<'info>(
+    account: &UncheckedAccount<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    bytes: usize,
+) -> Result<()> {
+    let space = 8 + bytes;
+    if account.data_len() == space {
+        return Ok(());
+    }
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let top_up = rent.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: account.to_account_info(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account.realloc(space, true)?;
+    Ok(())
+}
+
+/// Upgrades the account in place, returning its previous and current versions.
+fn migrate<T: AccountSerialize + AccountDeserialize>(
+    account: &UncheckedAccount,
+    version: impl Fn(&T) -> u8,
+    upgrade: impl FnOnce(&mut T),
+) -> Result<(u8, u8)> {
+    let mut data = account.try_borrow_mut_data()?;
+    let mut state = T::try_deserialize(&mut &data[..])?;
+    let previous_version = version(&state);
+    upgrade(&mut state);
+    let next_version = version(&state);
+    invariant!(previous_version < next_version, MigrateAlreadyCurrent);
+
+    let mut writer: &mut [u8] = &mut data;
+    state.try_serialize(&mut writer)?;
+    Ok((previous_version, next_version))
+}
+
+pub fn migrate_bank(ctx: Context<MigrateBank>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    realloc(
+        &accounts.bank,
+        &accounts.payer,
+        &accounts.system_program,
+        Bank::BYTES,
+    )?;
+    let (previous_version, version) =
+        migrate::<Bank>(&accounts.bank, |bank| bank.version, Bank::migrate)?;
+
+    emit!(MigrateBankEvent {
+        bank: accounts.bank.key(),
+        previous_version,
+        version,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+pub fn migrate_collateral(ctx: Context<MigrateCollateral>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    realloc(
+        &accounts.collateral,
+        &accounts.payer,
+        &accounts.system_program,
+        Collateral::BYTES,
+    )?;
+    let (previous_version, version) = migrate::<Collateral>(
+        &accounts.collateral,
+        |collateral| collateral.version,
+        Collateral::migrate,
+    )?;
+
+    emit!(MigrateCollateralEvent {
+        collateral: accounts.collateral.key(),
+        previous_version,
+        version,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
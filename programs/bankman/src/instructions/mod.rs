@@ -1,3 +1,37 @@
+pub mod approve_proposal;
+pub mod compound_fees;
+pub mod cover_bad_debt;
+pub mod cover_shortfall;
+pub mod create_proposal;
+pub mod distribute_fees;
+pub mod execute_proposal;
+pub mod harvest_rewards;
+pub mod migrate;
+pub mod new_insurance_fund;
+pub mod new_revenue_split;
+pub mod new_signer_set;
+pub mod roles;
+pub mod set_signer_set_owners;
+pub mod swap_rewards_to_backing;
 pub mod withdraw_author_fee;
+pub mod withdraw_author_fees;
+pub mod write_down_collateral;
 
+pub use approve_proposal::*;
+pub use compound_fees::*;
+pub use cover_bad_debt::*;
+pub use cover_shortfall::*;
+pub use create_proposal::*;
+pub use distribute_fees::*;
+pub use execute_proposal::*;
+pub use harvest_rewards::*;
+pub use migrate::*;
+pub use new_insurance_fund::*;
+pub use new_revenue_split::*;
+pub use new_signer_set::*;
+pub use roles::*;
+pub use set_signer_set_owners::*;
+pub use swap_rewards_to_backing::*;
 pub use withdraw_author_fee::*;
+pub use withdraw_author_fees::*;
+pub use write_down_collateral::*;
@@ -0,0 +1,56 @@
+use crate::*;
+
+/// Accounts for [bankman::new_insurance_fund].
+#[derive(Accounts)]
+pub struct NewInsuranceFund<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+
+    /// The [InsuranceFund] to create.
+    #[account(
+        init,
+        seeds = [
+            b"InsuranceFund".as_ref(),
+            bank.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + InsuranceFund::BYTES,
+        payer = payer
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+
+    /// Payer of the insurance fund initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for NewInsuranceFund<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.bankman, self.bank.bankman, UnauthorizedNotBankman);
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<NewInsuranceFund>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    insurance_fund.bank = ctx.accounts.bank.key();
+    insurance_fund.bump = unwrap_bump!(ctx, "insurance_fund");
+
+    let bank = &mut ctx.accounts.bank;
+    bank.insurance_fund = insurance_fund.key();
+
+    emit!(NewInsuranceFundEvent {
+        bank: bank.key(),
+        insurance_fund: insurance_fund.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
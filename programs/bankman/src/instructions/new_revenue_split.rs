@@ -0,0 +1,54 @@
+use crate::*;
+
+/// Accounts for [bankman::new_revenue_split].
+#[derive(Accounts)]
+pub struct NewRevenueSplit<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+
+    /// The [RevenueSplit] to create.
+    #[account(
+        init,
+        seeds = [
+            b"RevenueSplit".as_ref(),
+            bank.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + RevenueSplit::BYTES,
+        payer = payer
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+
+    /// Payer of the revenue split initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for NewRevenueSplit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.bankman, self.bank.bankman, UnauthorizedNotBankman);
+        Ok(())
+    }
+}
+
+pub fn handler(ctx: Context<NewRevenueSplit>) -> Result<()> {
+    let revenue_split = &mut ctx.accounts.revenue_split;
+    revenue_split.bank = ctx.accounts.bank.key();
+    revenue_split.bump = unwrap_bump!(ctx, "revenue_split");
+    // all fees go to the treasury until a split is set
+    revenue_split.treasury_bps = BPS_DENOMINATOR;
+
+    emit!(NewRevenueSplitEvent {
+        bank: revenue_split.bank,
+        revenue_split: revenue_split.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
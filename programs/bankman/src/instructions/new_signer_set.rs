@@ -0,0 +1,76 @@
+use crate::*;
+
+/// Accounts for [bankman::new_signer_set].
+#[derive(Accounts)]
+#[instruction(max_owners: u8)]
+pub struct NewSignerSet<'info> {
+    /// Base key used to derive the address of the [SignerSet].
+    pub base: Signer<'info>,
+
+    /// The [SignerSet] to create.
+    #[account(
+        init,
+        seeds = [
+            b"SignerSet".as_ref(),
+            base.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + SignerSet::space(max_owners),
+        payer = payer
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    /// Payer of the signer set initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> Validate<'info> for NewSignerSet<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<NewSignerSet>,
+    max_owners: u8,
+    threshold: u64,
+    owners: Vec<Pubkey>,
+) -> Result<()> {
+    validate_owners(&owners, threshold, max_owners)?;
+
+    let signer_set = &mut ctx.accounts.signer_set;
+    signer_set.base = ctx.accounts.base.key();
+    signer_set.bump = unwrap_bump!(ctx, "signer_set");
+    signer_set.threshold = threshold;
+    signer_set.max_owners = max_owners;
+    signer_set.owners = owners;
+
+    emit!(NewSignerSetEvent {
+        signer_set: signer_set.key(),
+        threshold,
+        owners: signer_set.owners.clone(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+/// Checks that `owners` are unique, fit in the account, and can meet the `threshold`.
+pub(crate) fn validate_owners(owners: &[Pubkey], threshold: u64, max_owners: u8) -> Result<()> {
+    invariant!(
+        owners.len() <= usize::from(max_owners),
+        SignerSetTooManyOwners
+    );
+    invariant!(
+        threshold > 0 && threshold <= owners.len() as u64,
+        SignerSetInvalidThreshold
+    );
+    for (i, owner) in owners.iter().enumerate() {
+        invariant!(!owners[..i].contains(owner), SignerSetDuplicateOwners);
+    }
+    Ok(())
+}
@@ -0,0 +1,125 @@
+use crate::*;
+
+/// Accounts for [bankman::set_risk_manager], [bankman::set_fee_manager], and [bankman::set_guardian].
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The pending holder of the role.
+    /// CHECK: Arbitrary.
+    pub next: UncheckedAccount<'info>,
+}
+
+/// Accounts for [bankman::accept_risk_manager], [bankman::accept_fee_manager], and [bankman::accept_guardian].
+#[derive(Accounts)]
+pub struct AcceptRole<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The pending holder of the role.
+    pub pending: Signer<'info>,
+}
+
+/// Accounts for [bankman::pause].
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::guardian] or the [Bank::bankman].
+    pub authority: Signer<'info>,
+}
+
+/// Accounts for [bankman::unpause].
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// The [Bank].
+    #[account(mut, has_one = bankman @ crate::ErrorCode::UnauthorizedNotBankman)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+}
+
+impl<'info> Validate<'info> for SetRole<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.bankman, self.bank.bankman, UnauthorizedNotBankman);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for AcceptRole<'info> {
+    fn validate(&self) -> Result<()> {
+        // the pending holder is checked against the role in [accept_role]
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for Pause<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(
+            self.authority.key() == self.bank.guardian || self.authority.key() == self.bank.bankman,
+            UnauthorizedNotGuardian
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for Unpause<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Sets the pending holder of a [BankRole].
+pub fn set_role(ctx: Context<SetRole>, role: BankRole) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+    let (_, pending) = bank.role_mut(role);
+    *pending = ctx.accounts.next.key();
+
+    emit!(SetPendingRoleEvent {
+        bank: bank.key(),
+        role,
+        pending: ctx.accounts.next.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+/// Accepts a [BankRole] as its pending holder.
+pub fn accept_role(ctx: Context<AcceptRole>, role: BankRole) -> Result<()> {
+    let bank = &mut ctx.accounts.bank;
+    let (current, pending) = bank.role_mut(role);
+    assert_keys_eq!(*pending, ctx.accounts.pending, UnauthorizedNotPendingRole);
+
+    let previous = *current;
+    *current = *pending;
+    *pending = Pubkey::default();
+
+    emit!(AcceptRoleEvent {
+        bank: bank.key(),
+        role,
+        previous,
+        next: ctx.accounts.pending.key(),
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+/// Pauses or unpauses the [Bank].
+pub fn set_paused(bank: &mut Account<Bank>, authority: Pubkey, paused: bool) -> Result<()> {
+    bank.paused = paused;
+
+    emit!(SetPausedEvent {
+        bank: bank.key(),
+        authority,
+        paused,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Accounts for [bankman::set_signer_set_owners].
+#[derive(Accounts)]
+pub struct SetSignerSetOwners<'info> {
+    /// The [SignerSet], which must sign via an executed [Proposal].
+    #[account(mut, signer)]
+    pub signer_set: Account<'info, SignerSet>,
+}
+
+impl<'info> Validate<'info> for SetSignerSetOwners<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn handler(
+    ctx: Context<SetSignerSetOwners>,
+    threshold: u64,
+    owners: Vec<Pubkey>,
+) -> Result<()> {
+    let signer_set = &mut ctx.accounts.signer_set;
+    validate_owners(&owners, threshold, signer_set.max_owners)?;
+    signer_set.threshold = threshold;
+    signer_set.owners = owners;
+    signer_set.owner_set_seqno = unwrap_int!(signer_set.owner_set_seqno.checked_add(1));
+
+    emit!(SetSignerSetOwnersEvent {
+        signer_set: signer_set.key(),
+        threshold,
+        owners: signer_set.owners.clone(),
+        owner_set_seqno: signer_set.owner_set_seqno,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
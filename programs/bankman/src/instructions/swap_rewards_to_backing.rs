@@ -0,0 +1,394 @@
+use crate::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use arrow_sunny::Arrow;
+
+/// Accounts for [bankman::swap_rewards_to_backing].
+#[derive(Accounts)]
+pub struct SwapRewardsToBacking<'info> {
+    /// The [Bank].
+    #[account(has_one = bankman @ crate::ErrorCode::UnauthorizedNotBankman)]
+    pub bank: Box<Account<'info, Bank>>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The [Collateral] whose [Arrow] receives the LP tokens.
+    #[account(has_one = bank)]
+    pub collateral: Box<Account<'info, Collateral>>,
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_collateral_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Rewards tokens of the [Bank] to swap.
+    #[account(mut)]
+    pub bank_rewards_tokens: Box<Account<'info, TokenAccount>>,
+    /// Stablecoin tokens of the [Bank], received from the swap and deposited into the LP.
+    #[account(mut)]
+    pub bank_stablecoin_tokens: Box<Account<'info, TokenAccount>>,
+    /// Tokens of the [Bank] of the other token in the LP. Nothing is deposited from this account.
+    #[account(mut)]
+    pub bank_quote_tokens: Box<Account<'info, TokenAccount>>,
+    /// LP tokens of the [Bank], received from the deposit and staked into the [Arrow].
+    #[account(mut)]
+    pub bank_lp_tokens: Box<Account<'info, TokenAccount>>,
+    /// Arrow tokens of the [Bank], which keep the tokens not added to the backing.
+    #[account(mut)]
+    pub bank_arrow_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// The Saber pool swapping the rewards for the stablecoin.
+    pub rewards_swap: RewardsSwapAccounts<'info>,
+    /// The Saber pool of the LP staked in the [Arrow].
+    pub lp_deposit: LpDepositAccounts<'info>,
+    /// Arrow staking accounts.
+    pub arrow_stake: ArrowStakeAccounts<'info>,
+
+    /// The Saber swap program.
+    pub saber_program: Program<'info, stable_swap_anchor::StableSwap>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for swapping rewards through a Saber pool.
+#[derive(Accounts)]
+pub struct RewardsSwapAccounts<'info> {
+    /// The Saber swap.
+    /// CHECK: validated by Saber.
+    pub swap: UncheckedAccount<'info>,
+    /// Authority of the Saber swap.
+    /// CHECK: validated by Saber.
+    pub swap_authority: UncheckedAccount<'info>,
+    /// Reserve of the rewards token.
+    /// CHECK: validated by Saber.
+    #[account(mut)]
+    pub input_reserve: UncheckedAccount<'info>,
+    /// Reserve of the stablecoin.
+    /// CHECK: validated by Saber.
+    #[account(mut)]
+    pub output_reserve: UncheckedAccount<'info>,
+    /// Admin fees of the stablecoin.
+    /// CHECK: validated by Saber.
+    #[account(mut)]
+    pub output_fees: UncheckedAccount<'info>,
+}
+
+/// Accounts for depositing into the Saber pool of an [Arrow].
+#[derive(Accounts)]
+pub struct LpDepositAccounts<'info> {
+    /// The Saber swap.
+    /// CHECK: validated by Saber.
+    pub swap: UncheckedAccount<'info>,
+    /// Authority of the Saber swap.
+    /// CHECK: validated by Saber.
+    pub swap_authority: UncheckedAccount<'info>,
+    /// Mint of the pool.
+    #[account(mut)]
+    pub pool_mint: Box<Account<'info, Mint>>,
+    /// Reserve of token A.
+    #[account(mut)]
+    pub reserve_a: Box<Account<'info, TokenAccount>>,
+    /// Reserve of token B.
+    #[account(mut)]
+    pub reserve_b: Box<Account<'info, TokenAccount>>,
+}
+
+/// Quarry staking accounts of an [Arrow] miner.
+#[derive(Accounts)]
+pub struct StakeAccounts<'info> {
+    /// Rewarder.
+    /// CHECK: validated by [arrow_sunny].
+    pub rewarder: UncheckedAccount<'info>,
+    /// Quarry.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub quarry: UncheckedAccount<'info>,
+    /// Miner.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub miner: UncheckedAccount<'info>,
+    /// Miner vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub miner_vault: UncheckedAccount<'info>,
+}
+
+/// Accounts for staking Saber LP tokens into an [Arrow].
+#[derive(Accounts)]
+pub struct ArrowStakeAccounts<'info> {
+    /// The [Arrow] of the [Collateral].
+    pub arrow: Box<Account<'info, Arrow>>,
+    /// Mint of the [Arrow].
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub arrow_mint: UncheckedAccount<'info>,
+    /// Saber LP tokens of the Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault_vendor_token_account: UncheckedAccount<'info>,
+    /// Vendor miner stake accounts.
+    pub vendor_stake: StakeAccounts<'info>,
+    /// Internal mint of the Sunny pool.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub internal_mint: UncheckedAccount<'info>,
+    /// Internal tokens of the Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault_internal_token_account: UncheckedAccount<'info>,
+    /// Internal miner stake accounts.
+    pub internal_stake: StakeAccounts<'info>,
+    /// Sunny pool.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+    /// Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+    /// [arrow_sunny] program.
+    pub arrow_program: Program<'info, arrow_sunny::program::ArrowSunny>,
+    /// Quarry mine program.
+    /// CHECK: validated by [arrow_sunny].
+    pub mine_program: UncheckedAccount<'info>,
+    /// Sunny program.
+    /// CHECK: validated by [arrow_sunny].
+    pub sunny_program: UncheckedAccount<'info>,
+    /// Clock sysvar.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+impl<'info> Validate<'info> for SwapRewardsToBacking<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.collateral.kind == CollateralKind::SaberLpArrow,
+            CollateralKindMismatch
+        );
+        let arrow = &self.arrow_stake.arrow;
+        assert_keys_eq!(arrow.mint, self.collateral.mint);
+        assert_keys_eq!(arrow.vendor_miner.mint, self.lp_deposit.pool_mint);
+
+        assert_keys_eq!(self.crate_collateral_tokens.owner, self.bank.crate_token);
+        assert_keys_eq!(self.crate_collateral_tokens.mint, self.collateral.mint);
+
+        let stablecoin_mint = self.bank_stablecoin_tokens.mint;
+        let quote_mint = self.bank_quote_tokens.mint;
+        let reserve_mints = [
+            self.lp_deposit.reserve_a.mint,
+            self.lp_deposit.reserve_b.mint,
+        ];
+        invariant!(
+            reserve_mints == [stablecoin_mint, quote_mint]
+                || reserve_mints == [quote_mint, stablecoin_mint],
+            SwapRewardsTokenNotInPool
+        );
+
+        for owner in [
+            self.bank_rewards_tokens.owner,
+            self.bank_stablecoin_tokens.owner,
+            self.bank_quote_tokens.owner,
+            self.bank_lp_tokens.owner,
+            self.bank_arrow_tokens.owner,
+        ] {
+            assert_keys_eq!(owner, self.bank);
+        }
+        assert_keys_eq!(self.bank_lp_tokens.mint, self.lp_deposit.pool_mint);
+        assert_keys_eq!(self.bank_arrow_tokens.mint, self.collateral.mint);
+        // the swap and its reserves are validated by Saber
+        Ok(())
+    }
+}
+
+impl<'info> SwapRewardsToBacking<'info> {
+    fn swap_rewards(&self, amount_in: u64, min_stablecoin_amount: u64) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(self.bank)];
+        let swap = &self.rewards_swap;
+        stable_swap_anchor::swap(
+            CpiContext::new_with_signer(
+                self.saber_program.to_account_info(),
+                stable_swap_anchor::Swap {
+                    user: stable_swap_anchor::SwapUserContext {
+                        token_program: self.token_program.to_account_info(),
+                        swap_authority: swap.swap_authority.to_account_info(),
+                        user_authority: self.bank.to_account_info(),
+                        swap: swap.swap.to_account_info(),
+                    },
+                    input: stable_swap_anchor::SwapToken {
+                        user: self.bank_rewards_tokens.to_account_info(),
+                        reserve: swap.input_reserve.to_account_info(),
+                    },
+                    output: stable_swap_anchor::SwapOutput {
+                        user_token: stable_swap_anchor::SwapToken {
+                            user: self.bank_stablecoin_tokens.to_account_info(),
+                            reserve: swap.output_reserve.to_account_info(),
+                        },
+                        fees: swap.output_fees.to_account_info(),
+                    },
+                },
+                signer_seeds,
+            ),
+            amount_in,
+            min_stablecoin_amount,
+        )
+    }
+
+    fn deposit_stablecoin(&self, stablecoin_amount: u64, min_lp_amount: u64) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(self.bank)];
+        let deposit = &self.lp_deposit;
+        let (user_a, user_b, token_a_amount, token_b_amount) =
+            if deposit.reserve_a.mint == self.bank_stablecoin_tokens.mint {
+                (
+                    &self.bank_stablecoin_tokens,
+                    &self.bank_quote_tokens,
+                    stablecoin_amount,
+                    0,
+                )
+            } else {
+                (
+                    &self.bank_quote_tokens,
+                    &self.bank_stablecoin_tokens,
+                    0,
+                    stablecoin_amount,
+                )
+            };
+        stable_swap_anchor::deposit(
+            CpiContext::new_with_signer(
+                self.saber_program.to_account_info(),
+                stable_swap_anchor::Deposit {
+                    user: stable_swap_anchor::SwapUserContext {
+                        token_program: self.token_program.to_account_info(),
+                        swap_authority: deposit.swap_authority.to_account_info(),
+                        user_authority: self.bank.to_account_info(),
+                        swap: deposit.swap.to_account_info(),
+                    },
+                    input_a: stable_swap_anchor::SwapToken {
+                        user: user_a.to_account_info(),
+                        reserve: deposit.reserve_a.to_account_info(),
+                    },
+                    input_b: stable_swap_anchor::SwapToken {
+                        user: user_b.to_account_info(),
+                        reserve: deposit.reserve_b.to_account_info(),
+                    },
+                    pool_mint: deposit.pool_mint.to_account_info(),
+                    output_lp: self.bank_lp_tokens.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            token_a_amount,
+            token_b_amount,
+            min_lp_amount,
+        )
+    }
+
+    fn stake_lp(&self, lp_amount: u64) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(self.bank)];
+        let stake = &self.arrow_stake;
+        arrow_sunny::cpi::deposit_vendor(
+            CpiContext::new_with_signer(
+                stake.arrow_program.to_account_info(),
+                arrow_sunny::cpi::accounts::DepositVendor {
+                    arrow: stake.arrow.to_account_info(),
+                    arrow_stake: arrow_sunny::cpi::accounts::ArrowStake {
+                        arrow_mint: stake.arrow_mint.to_account_info(),
+                        depositor: self.bank.to_account_info(),
+                        depositor_arrow_tokens: self.bank_arrow_tokens.to_account_info(),
+                    },
+                    depositor_staked_tokens: self.bank_lp_tokens.to_account_info(),
+                    vault_vendor_token_account: stake.vault_vendor_token_account.to_account_info(),
+                    vendor_stake: stake.vendor_stake.to_cpi_accounts(),
+                    pool: stake.pool.to_account_info(),
+                    vault: stake.vault.to_account_info(),
+                    token_program: self.token_program.to_account_info(),
+                    mine_program: stake.mine_program.to_account_info(),
+                    sunny_program: stake.sunny_program.to_account_info(),
+                    clock: stake.clock.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            lp_amount,
+        )?;
+        arrow_sunny::cpi::stake_internal(CpiContext::new(
+            stake.arrow_program.to_account_info(),
+            arrow_sunny::cpi::accounts::StakeInternal {
+                arrow: stake.arrow.to_account_info(),
+                internal_mint: stake.internal_mint.to_account_info(),
+                vault_internal_token_account: stake.vault_internal_token_account.to_account_info(),
+                internal_stake: stake.internal_stake.to_cpi_accounts(),
+                pool: stake.pool.to_account_info(),
+                vault: stake.vault.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+                mine_program: stake.mine_program.to_account_info(),
+                sunny_program: stake.sunny_program.to_account_info(),
+                clock: stake.clock.to_account_info(),
+            },
+        ))
+    }
+
+    fn add_to_backing(&self, arrow_amount: u64) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(self.bank)];
+        token::transfer(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.bank_arrow_tokens.to_account_info(),
+                    to: self.crate_collateral_tokens.to_account_info(),
+                    authority: self.bank.to_account_info(),
+                },
+            )
+            .with_signer(signer_seeds),
+            arrow_amount,
+        )
+    }
+}
+
+impl<'info> StakeAccounts<'info> {
+    fn to_cpi_accounts(&self) -> arrow_sunny::cpi::accounts::StakeCommon<'info> {
+        arrow_sunny::cpi::accounts::StakeCommon {
+            rewarder: self.rewarder.to_account_info(),
+            quarry: self.quarry.to_account_info(),
+            miner: self.miner.to_account_info(),
+            miner_vault: self.miner_vault.to_account_info(),
+        }
+    }
+}
+
+pub fn handler(
+    ctx: Context<SwapRewardsToBacking>,
+    amount_in: u64,
+    min_stablecoin_amount: u64,
+    min_lp_amount: u64,
+    to_backing: bool,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+
+    let stablecoin_before = accounts.bank_stablecoin_tokens.amount;
+    accounts.swap_rewards(amount_in, min_stablecoin_amount)?;
+    accounts.bank_stablecoin_tokens.reload()?;
+    let stablecoin_amount = unwrap_int!(accounts
+        .bank_stablecoin_tokens
+        .amount
+        .checked_sub(stablecoin_before));
+
+    let lp_before = accounts.bank_lp_tokens.amount;
+    accounts.deposit_stablecoin(stablecoin_amount, min_lp_amount)?;
+    accounts.bank_lp_tokens.reload()?;
+    let lp_amount = unwrap_int!(accounts.bank_lp_tokens.amount.checked_sub(lp_before));
+
+    let arrow_before = accounts.bank_arrow_tokens.amount;
+    accounts.stake_lp(lp_amount)?;
+    accounts.bank_arrow_tokens.reload()?;
+    let arrow_amount = unwrap_int!(accounts.bank_arrow_tokens.amount.checked_sub(arrow_before));
+
+    if to_backing {
+        accounts.add_to_backing(arrow_amount)?;
+    }
+
+    emit!(SwapRewardsToBackingEvent {
+        bank: accounts.bank.key(),
+        collateral: accounts.collateral.key(),
+        rewards_mint: accounts.bank_rewards_tokens.mint,
+        rewards_amount: amount_in,
+        stablecoin_amount,
+        lp_amount,
+        to_backing,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
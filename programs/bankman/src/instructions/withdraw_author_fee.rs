@@ -5,17 +5,17 @@ use anchor_spl::token::{self, Token, TokenAccount};
 #[derive(Accounts)]
 pub struct WithdrawAuthorFee<'info> {
     /// The [Bank].
-    #[account(has_one = bankman @ crate::ErrorCode::UnauthorizedNotBankman)]
+    #[account(has_one = fee_manager @ crate::ErrorCode::UnauthorizedNotFeeManager)]
     pub bank: Account<'info, Bank>,
-    /// The [Bank::bankman].
-    pub bankman: Signer<'info>,
+    /// The [Bank::fee_manager].
+    pub fee_manager: Signer<'info>,
     /// The [Collateral].
     #[account(has_one = bank)]
     pub collateral: Account<'info, Collateral>,
     /// Author fees.
     #[account(mut, constraint = author_fees.mint == collateral.mint)]
     pub author_fees: Account<'info, TokenAccount>,
-    /// Account to send the author fees to.
+    /// Account to send the author fees to. Must be owned by the [Bank::treasury].
     #[account(mut)]
     pub destination: Account<'info, TokenAccount>,
     /// The [Token] program.
@@ -24,30 +24,77 @@ pub struct WithdrawAuthorFee<'info> {
 
 impl<'info> Validate<'info> for WithdrawAuthorFee<'info> {
     fn validate(&self) -> Result<()> {
-        assert_keys_neq!(self.author_fees, self.destination);
-        assert_keys_eq!(self.author_fees.owner, self.bank);
-        assert_keys_eq!(self.author_fees.mint, self.collateral.mint);
-        Ok(())
+        validate_author_fee_accounts(
+            &self.bank,
+            &self.collateral,
+            &self.author_fees,
+            &self.destination,
+        )
     }
 }
 
 pub fn handler(ctx: Context<WithdrawAuthorFee>, amount: u64) -> Result<()> {
-    let signer_seeds: &[&[&[u8]]] = &[&[
-        b"Bank".as_ref(),
-        &ctx.accounts.bank.crate_token.to_bytes(),
-        &[ctx.accounts.bank.bump],
-    ]];
+    transfer_author_fee(
+        &ctx.accounts.bank,
+        &ctx.accounts.collateral,
+        &ctx.accounts.author_fees,
+        &ctx.accounts.destination,
+        &ctx.accounts.token_program,
+        amount,
+    )
+}
+
+/// Checks that `author_fees` holds the [Bank]'s fees for the [Collateral]
+/// and that `destination` is a [Bank::treasury] account that can receive them.
+pub(crate) fn validate_author_fee_accounts(
+    bank: &Account<Bank>,
+    collateral: &Account<Collateral>,
+    author_fees: &Account<TokenAccount>,
+    destination: &Account<TokenAccount>,
+) -> Result<()> {
+    assert_keys_eq!(collateral.bank, bank.key());
+    assert_keys_neq!(author_fees.key(), destination.key());
+    assert_keys_eq!(author_fees.owner, bank.key());
+    assert_keys_eq!(author_fees.mint, collateral.mint);
+    assert_keys_eq!(destination.mint, collateral.mint);
+    assert_keys_eq!(
+        destination.owner,
+        bank.treasury,
+        WithdrawAuthorFeeDestinationNotTreasury
+    );
+    Ok(())
+}
+
+/// Transfers `amount` author fees out of the [Bank] and emits a [WithdrawAuthorFeeEvent].
+pub(crate) fn transfer_author_fee<'info>(
+    bank: &Account<'info, Bank>,
+    collateral: &Account<'info, Collateral>,
+    author_fees: &Account<'info, TokenAccount>,
+    destination: &Account<'info, TokenAccount>,
+    token_program: &Program<'info, Token>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[gen_bank_signer_seeds!(bank)];
     token::transfer(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
+            token_program.to_account_info(),
             token::Transfer {
-                from: ctx.accounts.author_fees.to_account_info(),
-                to: ctx.accounts.destination.to_account_info(),
-                authority: ctx.accounts.bank.to_account_info(),
+                from: author_fees.to_account_info(),
+                to: destination.to_account_info(),
+                authority: bank.to_account_info(),
             },
         )
         .with_signer(signer_seeds),
         amount,
     )?;
+
+    emit!(WithdrawAuthorFeeEvent {
+        bank: bank.key(),
+        collateral: collateral.key(),
+        mint: collateral.mint,
+        destination: destination.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp
+    });
     Ok(())
 }
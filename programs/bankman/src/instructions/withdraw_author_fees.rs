@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Number of remaining accounts per [Collateral] in [bankman::withdraw_author_fees].
+pub const WITHDRAW_AUTHOR_FEES_ACCOUNTS_PER_COLLATERAL: usize = 3;
+
+/// Accounts for [bankman::withdraw_author_fees].
+///
+/// The remaining accounts are groups of
+/// `[collateral, author_fees, destination]`,
+/// one group per [Collateral] to withdraw from.
+#[derive(Accounts)]
+pub struct WithdrawAuthorFees<'info> {
+    /// The [Bank].
+    #[account(has_one = fee_manager @ crate::ErrorCode::UnauthorizedNotFeeManager)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::fee_manager].
+    pub fee_manager: Signer<'info>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for WithdrawAuthorFees<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawAuthorFees<'info>>,
+    amounts: Option<Vec<u64>>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    invariant!(
+        !remaining.is_empty()
+            && remaining.len() % WITHDRAW_AUTHOR_FEES_ACCOUNTS_PER_COLLATERAL == 0,
+        WithdrawAuthorFeesInvalidAccounts
+    );
+    let num_collaterals = remaining.len() / WITHDRAW_AUTHOR_FEES_ACCOUNTS_PER_COLLATERAL;
+    if let Some(amounts) = &amounts {
+        invariant!(
+            amounts.len() == num_collaterals,
+            WithdrawAuthorFeesAmountsMismatch
+        );
+    }
+
+    for (i, group) in remaining
+        .chunks_exact(WITHDRAW_AUTHOR_FEES_ACCOUNTS_PER_COLLATERAL)
+        .enumerate()
+    {
+        let collateral: Account<Collateral> = Account::try_from(&group[0])?;
+        let author_fees: Account<TokenAccount> = Account::try_from(&group[1])?;
+        let destination: Account<TokenAccount> = Account::try_from(&group[2])?;
+        invariant!(group[1].is_writable && group[2].is_writable);
+        validate_author_fee_accounts(&ctx.accounts.bank, &collateral, &author_fees, &destination)?;
+
+        let amount = match &amounts {
+            Some(amounts) => amounts[i],
+            None => author_fees.amount,
+        };
+        if amount == 0 {
+            continue;
+        }
+        transfer_author_fee(
+            &ctx.accounts.bank,
+            &collateral,
+            &author_fees,
+            &destination,
+            &ctx.accounts.token_program,
+            amount,
+        )?;
+    }
+
+    Ok(())
+}
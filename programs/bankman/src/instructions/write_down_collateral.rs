@@ -0,0 +1,77 @@
+use crate::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+/// Accounts for [bankman::write_down_collateral].
+#[derive(Accounts)]
+pub struct WriteDownCollateral<'info> {
+    /// The [Bank].
+    #[account(mut, has_one = bankman @ crate::ErrorCode::UnauthorizedNotBankman)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The retired [Collateral].
+    #[account(mut, has_one = bank)]
+    pub collateral: Account<'info, Collateral>,
+    /// [Mint] of the [Collateral].
+    pub collateral_mint: Account<'info, Mint>,
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    pub crate_collateral_tokens: Account<'info, TokenAccount>,
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Account<'info, Mint>,
+    /// Surplus buffer: a $CASH token account owned by the [Bank].
+    #[account(mut)]
+    pub surplus_buffer: Account<'info, TokenAccount>,
+    /// The [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> Validate<'info> for WriteDownCollateral<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(self.collateral.hard_cap == 0, WriteDownCollateralNotRetired);
+        assert_keys_eq!(self.crate_collateral_tokens.owner, self.bank.crate_token);
+        assert_keys_eq!(self.crate_collateral_tokens.mint, self.collateral.mint);
+        validate_surplus_buffer(&self.bank, &self.crate_mint, &self.surplus_buffer)
+    }
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WriteDownCollateral<'info>>,
+    amount: u64,
+) -> Result<()> {
+    // the total written down may not exceed the value of the backing
+    let backing_value = pricing::cash_value(
+        &ctx.accounts.collateral,
+        &ctx.accounts.collateral_mint,
+        ctx.accounts.crate_collateral_tokens.amount,
+        ctx.remaining_accounts,
+    )?;
+    let accounts = ctx.accounts;
+    let collateral = &mut accounts.collateral;
+    collateral.written_down = unwrap_int!(collateral.written_down.checked_add(amount));
+    invariant!(
+        collateral.written_down <= backing_value,
+        WriteDownCollateralExceedsBacking
+    );
+
+    let bank = &mut accounts.bank;
+    bank.bad_debt = unwrap_int!(bank.bad_debt.checked_add(amount));
+    bank.cover_bad_debt_from_surplus();
+
+    emit!(WriteDownCollateralEvent {
+        bank: bank.key(),
+        collateral: collateral.key(),
+        mint: collateral.mint,
+        amount,
+        surplus: bank.surplus,
+        bad_debt: bank.bad_debt,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    burn_from_surplus_buffer(
+        bank,
+        &accounts.crate_mint,
+        &accounts.surplus_buffer,
+        &accounts.token_program,
+    )
+}
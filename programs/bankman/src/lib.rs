@@ -1,10 +1,17 @@
 //! Allowlist for $CASH collateral tokens.
 #![deny(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
+#![allow(clippy::result_large_err)]
+
+mod macros;
 
 mod account_validators;
+pub mod addresses;
 mod events;
 mod instructions;
+mod math;
+mod pricing;
+mod reserve;
 mod state;
 
 use anchor_lang::prelude::*;
@@ -13,11 +20,16 @@ use vipers::prelude::*;
 
 pub use events::*;
 use instructions::*;
+pub use math::*;
+pub use reserve::*;
 pub use state::*;
 
 /// Number of decimals of $CASH.
 pub const CASH_DECIMALS: u8 = 6;
 
+/// Number of seconds that must elapse before a new treasury may be applied.
+pub const TREASURY_TIMELOCK_SECONDS: i64 = 60 * 60 * 24 * 2;
+
 declare_id!("BANKhiCgEYd7QmcWwPLkqvTuuLN6qEwXDZgTe6HEbwv1");
 
 /// [bankman] program.
@@ -73,6 +85,11 @@ pub mod bankman {
         bank.crate_mint = ctx.accounts.crate_mint.key();
         bank.curator = ctx.accounts.admin.key();
         bank.bankman = ctx.accounts.admin.key();
+        bank.treasury = ctx.accounts.admin.key();
+        bank.risk_manager = ctx.accounts.admin.key();
+        bank.fee_manager = ctx.accounts.admin.key();
+        bank.guardian = ctx.accounts.admin.key();
+        bank.version = Bank::VERSION;
 
         emit!(NewBankEvent {
             bank: bank.key(),
@@ -85,25 +102,36 @@ pub mod bankman {
 
     /// Adds a new collateral pool to a [Bank].
     #[access_control(ctx.accounts.validate())]
-    pub fn authorize_collateral(ctx: Context<AuthorizeCollateral>, _bump: u8) -> Result<()> {
+    pub fn authorize_collateral(
+        ctx: Context<AuthorizeCollateral>,
+        _bump: u8,
+        kind: CollateralKind,
+    ) -> Result<()> {
         let bank = &ctx.accounts.bank;
 
         let collateral = &mut ctx.accounts.collateral;
         collateral.bank = bank.key();
         collateral.mint = ctx.accounts.mint.key();
         collateral.bump = unwrap_bump!(ctx, "collateral");
+        collateral.kind = kind;
+        collateral.version = Collateral::VERSION;
+        collateral.authorized_at = Clock::get()?.unix_timestamp;
+        collateral.authorized_by = ctx.accounts.curator.key();
 
         emit!(AddCollateralEvent {
             bank: bank.key(),
             collateral: collateral.key(),
             curator: bank.curator,
             mint: collateral.mint,
-            timestamp: Clock::get()?.unix_timestamp
+            kind,
+            timestamp: collateral.authorized_at
         });
         Ok(())
     }
 
-    /// Adds a new collateral pool to a [Bank].
+    /// Sets the hard cap on the number of tokens of a [Collateral] backing $CASH.
+    ///
+    /// Only the [Bank::risk_manager] may call this.
     #[access_control(ctx.accounts.validate())]
     pub fn set_collateral_hard_cap(
         ctx: Context<SetCollateralHardCap>,
@@ -121,6 +149,85 @@ pub mod bankman {
         Ok(())
     }
 
+    /// Sets the reserve account used to value a [CollateralKind::YieldBearing] collateral,
+    /// and the lending program which must own it.
+    ///
+    /// Only the [Bank::risk_manager] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_collateral_reserve(ctx: Context<SetCollateralReserve>) -> Result<()> {
+        let collateral = &mut ctx.accounts.collateral;
+        collateral.reserve = ctx.accounts.reserve.key();
+        collateral.lending_program = ctx.accounts.lending_program.key();
+
+        emit!(SetCollateralReserveEvent {
+            bank: ctx.accounts.bank.key(),
+            collateral: collateral.key(),
+            reserve: collateral.reserve,
+            lending_program: collateral.lending_program,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Creates the [CollateralMetadata] of a [Collateral], which is empty until set by the curator.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_collateral_metadata(ctx: Context<NewCollateralMetadata>) -> Result<()> {
+        let metadata = &mut ctx.accounts.metadata;
+        metadata.collateral = ctx.accounts.collateral.key();
+        metadata.bump = unwrap_bump!(ctx, "metadata");
+
+        emit!(NewCollateralMetadataEvent {
+            bank: ctx.accounts.bank.key(),
+            collateral: metadata.collateral,
+            metadata: metadata.key(),
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Sets the [CollateralMetadata] of a [Collateral].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_collateral_metadata(
+        ctx: Context<SetCollateralMetadata>,
+        name: String,
+        underlying_mints: Vec<Pubkey>,
+        saber_swap: Pubkey,
+        risk_tier: u8,
+    ) -> Result<()> {
+        invariant!(
+            name.len() <= CollateralMetadata::MAX_NAME_LEN,
+            CollateralMetadataNameTooLong
+        );
+        invariant!(
+            underlying_mints.len() <= CollateralMetadata::MAX_UNDERLYING_MINTS,
+            CollateralMetadataTooManyMints
+        );
+
+        let metadata = &mut ctx.accounts.metadata;
+        metadata.name = name;
+        metadata.underlying_mints = underlying_mints;
+        metadata.saber_swap = saber_swap;
+        metadata.risk_tier = risk_tier;
+
+        let collateral = &ctx.accounts.collateral;
+        emit!(SetCollateralMetadataEvent {
+            bank: ctx.accounts.bank.key(),
+            collateral: collateral.key(),
+            curator: ctx.accounts.curator.key(),
+            mint: collateral.mint,
+            name: metadata.name.clone(),
+            underlying_mints: metadata.underlying_mints.clone(),
+            saber_swap,
+            risk_tier,
+            authorized_at: collateral.authorized_at,
+            authorized_by: collateral.authorized_by,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
     /// Sets the curator.
     #[access_control(ctx.accounts.validate())]
     pub fn set_curator(ctx: Context<SetCurator>) -> Result<()> {
@@ -145,6 +252,8 @@ pub mod bankman {
         let bank = &mut ctx.accounts.bank;
         let previous_bankman = bank.bankman;
         bank.bankman = ctx.accounts.next_bankman.key();
+        // the new bankman is not necessarily the smart wallet of the governor
+        bank.governor = Pubkey::default();
 
         emit!(SetBankmanEvent {
             bank: bank.key(),
@@ -156,11 +265,441 @@ pub mod bankman {
         Ok(())
     }
 
+    /// Upgrades a [Bank] created by a previous version of the program to the current layout,
+    /// reallocating it in place.
+    ///
+    /// Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_bank(ctx: Context<MigrateBank>) -> Result<()> {
+        instructions::migrate::migrate_bank(ctx)
+    }
+
+    /// Upgrades a [Collateral] created by a previous version of the program to the current layout,
+    /// reallocating it in place.
+    ///
+    /// Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn migrate_collateral(ctx: Context<MigrateCollateral>) -> Result<()> {
+        instructions::migrate::migrate_collateral(ctx)
+    }
+
+    /// Hands the [Bank::bankman] role to the smart wallet of a governor.
+    ///
+    /// The governor must be owned by the Tribeca govern program ([addresses::GOVERN_PROGRAM_ID]),
+    /// and the smart wallet must be the one recorded in the governor, so bankman instructions
+    /// may only be signed when an approved proposal is executed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_governor(ctx: Context<SetGovernor>) -> Result<()> {
+        let bank = &mut ctx.accounts.bank;
+        let previous_bankman = bank.bankman;
+        bank.governor = ctx.accounts.governor.key();
+        bank.bankman = ctx.accounts.smart_wallet.key();
+
+        emit!(SetGovernorEvent {
+            bank: bank.key(),
+            governor: bank.governor,
+            governor_program: *ctx.accounts.governor.owner,
+            previous_bankman,
+            bankman: bank.bankman,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Appoints a new [Bank::risk_manager], who must accept the role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_risk_manager(ctx: Context<SetRole>) -> Result<()> {
+        instructions::roles::set_role(ctx, BankRole::RiskManager)
+    }
+
+    /// Accepts the [Bank::risk_manager] role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn accept_risk_manager(ctx: Context<AcceptRole>) -> Result<()> {
+        instructions::roles::accept_role(ctx, BankRole::RiskManager)
+    }
+
+    /// Appoints a new [Bank::fee_manager], who must accept the role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_fee_manager(ctx: Context<SetRole>) -> Result<()> {
+        instructions::roles::set_role(ctx, BankRole::FeeManager)
+    }
+
+    /// Accepts the [Bank::fee_manager] role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn accept_fee_manager(ctx: Context<AcceptRole>) -> Result<()> {
+        instructions::roles::accept_role(ctx, BankRole::FeeManager)
+    }
+
+    /// Appoints a new [Bank::guardian], who must accept the role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_guardian(ctx: Context<SetRole>) -> Result<()> {
+        instructions::roles::set_role(ctx, BankRole::Guardian)
+    }
+
+    /// Accepts the [Bank::guardian] role.
+    #[access_control(ctx.accounts.validate())]
+    pub fn accept_guardian(ctx: Context<AcceptRole>) -> Result<()> {
+        instructions::roles::accept_role(ctx, BankRole::Guardian)
+    }
+
+    /// Pauses the [Bank], preventing $CASH from being printed, burned, or flash minted.
+    ///
+    /// The guardian or the bankman may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn pause(ctx: Context<Pause>) -> Result<()> {
+        instructions::roles::set_paused(&mut ctx.accounts.bank, ctx.accounts.authority.key(), true)
+    }
+
+    /// Unpauses the [Bank].
+    ///
+    /// Only the bankman may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn unpause(ctx: Context<Unpause>) -> Result<()> {
+        instructions::roles::set_paused(&mut ctx.accounts.bank, ctx.accounts.bankman.key(), false)
+    }
+
+    /// Proposes a new treasury, which can be applied after [TREASURY_TIMELOCK_SECONDS].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_pending_treasury(ctx: Context<SetPendingTreasury>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let bank = &mut ctx.accounts.bank;
+        bank.pending_treasury = ctx.accounts.next_treasury.key();
+        bank.pending_treasury_eta = unwrap_int!(now.checked_add(TREASURY_TIMELOCK_SECONDS));
+
+        emit!(SetPendingTreasuryEvent {
+            bank: bank.key(),
+            pending_treasury: bank.pending_treasury,
+            treasury: bank.treasury,
+            eta: bank.pending_treasury_eta,
+            timestamp: now
+        });
+
+        Ok(())
+    }
+
+    /// Applies the pending treasury once its timelock has elapsed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_pending_treasury(ctx: Context<ApplyPendingTreasury>) -> Result<()> {
+        let bank = &mut ctx.accounts.bank;
+        let previous_treasury = bank.treasury;
+        bank.treasury = bank.pending_treasury;
+        bank.pending_treasury = Pubkey::default();
+        bank.pending_treasury_eta = 0;
+
+        emit!(SetTreasuryEvent {
+            bank: bank.key(),
+            previous_treasury,
+            treasury: bank.treasury,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Enables or disables [bankman::compound_fees] for the [Bank].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_compound_fees(ctx: Context<SetCompoundFees>, compound_fees: bool) -> Result<()> {
+        let bank = &mut ctx.accounts.bank;
+        bank.compound_fees = compound_fees;
+
+        emit!(SetCompoundFeesEvent {
+            bank: bank.key(),
+            compound_fees,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Deposits the accrued author fees of a [Collateral] into the backing of $CASH
+    /// without printing any $CASH, raising the collateral ratio.
+    ///
+    /// Anyone may call this if [Bank::compound_fees] is enabled. The entire balance
+    /// of the author fee account is compounded, so enabling compounding routes all
+    /// fees of the Bank into the backing until it is disabled again. The accounts
+    /// needed to price the collateral are passed as remaining accounts, as described
+    /// in [pricing].
+    ///
+    /// The value compounded first covers any [Bank::bad_debt], and the rest is added
+    /// to the [Bank::surplus].
+    #[access_control(ctx.accounts.validate())]
+    pub fn compound_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, CompoundFees<'info>>,
+    ) -> Result<()> {
+        instructions::compound_fees::handler(ctx)
+    }
+
+    /// Writes down the value of a retired [Collateral], recording it as bad debt.
+    ///
+    /// The total written down may not exceed the value of the backing at the nominal
+    /// price of the collateral. The accounts needed to price it are passed as remaining
+    /// accounts, as described in [pricing].
+    ///
+    /// As much of the shortfall as possible is immediately covered from the [Bank::surplus],
+    /// then from the surplus buffer.
+    #[access_control(ctx.accounts.validate())]
+    pub fn write_down_collateral<'info>(
+        ctx: Context<'_, '_, '_, 'info, WriteDownCollateral<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::write_down_collateral::handler(ctx, amount)
+    }
+
+    /// Covers the [Bank::bad_debt] by burning $CASH from the surplus buffer.
+    ///
+    /// Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cover_bad_debt(ctx: Context<CoverBadDebt>) -> Result<()> {
+        instructions::cover_bad_debt::handler(ctx)
+    }
+
+    /// Creates the [InsuranceFund] of a [Bank].
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_insurance_fund(ctx: Context<NewInsuranceFund>) -> Result<()> {
+        instructions::new_insurance_fund::handler(ctx)
+    }
+
+    /// Creates the [RevenueSplit] of a [Bank]. All fees go to the treasury until a split is set.
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_revenue_split(ctx: Context<NewRevenueSplit>) -> Result<()> {
+        instructions::new_revenue_split::handler(ctx)
+    }
+
+    /// Proposes how author fees are split by [bankman::distribute_fees], and to whom.
+    /// The split can be applied after [TREASURY_TIMELOCK_SECONDS].
+    ///
+    /// The portions must sum to [BPS_DENOMINATOR], and the savings vault must be the
+    /// savings vault of the [Bank]. Only the [Bank::fee_manager] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_pending_revenue_split(
+        ctx: Context<SetPendingRevenueSplit>,
+        treasury_bps: u16,
+        insurance_fund_bps: u16,
+        savings_bps: u16,
+        stakers_bps: u16,
+    ) -> Result<()> {
+        let total_bps = u32::from(treasury_bps)
+            + u32::from(insurance_fund_bps)
+            + u32::from(savings_bps)
+            + u32::from(stakers_bps);
+        invariant!(
+            total_bps == u32::from(BPS_DENOMINATOR),
+            RevenueSplitInvalidBps
+        );
+        invariant!(
+            insurance_fund_bps == 0 || ctx.accounts.bank.insurance_fund != Pubkey::default(),
+            InsuranceFundNotCreated
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let revenue_split = &mut ctx.accounts.revenue_split;
+        revenue_split.pending_treasury_bps = treasury_bps;
+        revenue_split.pending_insurance_fund_bps = insurance_fund_bps;
+        revenue_split.pending_savings_bps = savings_bps;
+        revenue_split.pending_stakers_bps = stakers_bps;
+        revenue_split.pending_savings_vault = ctx.accounts.savings_vault.key();
+        revenue_split.pending_stakers = ctx.accounts.stakers.key();
+        revenue_split.pending_eta = unwrap_int!(now.checked_add(TREASURY_TIMELOCK_SECONDS));
+
+        emit!(SetPendingRevenueSplitEvent {
+            bank: ctx.accounts.bank.key(),
+            treasury_bps,
+            insurance_fund_bps,
+            savings_bps,
+            stakers_bps,
+            savings_vault: revenue_split.pending_savings_vault,
+            stakers: revenue_split.pending_stakers,
+            eta: revenue_split.pending_eta,
+            timestamp: now
+        });
+
+        Ok(())
+    }
+
+    /// Applies the pending revenue split once its timelock has elapsed.
+    ///
+    /// Only the [Bank::fee_manager] may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn apply_pending_revenue_split(ctx: Context<ApplyPendingRevenueSplit>) -> Result<()> {
+        let revenue_split = &mut ctx.accounts.revenue_split;
+        revenue_split.treasury_bps = revenue_split.pending_treasury_bps;
+        revenue_split.insurance_fund_bps = revenue_split.pending_insurance_fund_bps;
+        revenue_split.savings_bps = revenue_split.pending_savings_bps;
+        revenue_split.stakers_bps = revenue_split.pending_stakers_bps;
+        revenue_split.savings_vault = revenue_split.pending_savings_vault;
+        revenue_split.stakers = revenue_split.pending_stakers;
+        revenue_split.pending_treasury_bps = 0;
+        revenue_split.pending_insurance_fund_bps = 0;
+        revenue_split.pending_savings_bps = 0;
+        revenue_split.pending_stakers_bps = 0;
+        revenue_split.pending_savings_vault = Pubkey::default();
+        revenue_split.pending_stakers = Pubkey::default();
+        revenue_split.pending_eta = 0;
+
+        emit!(SetRevenueSplitEvent {
+            bank: ctx.accounts.bank.key(),
+            treasury_bps: revenue_split.treasury_bps,
+            insurance_fund_bps: revenue_split.insurance_fund_bps,
+            savings_bps: revenue_split.savings_bps,
+            stakers_bps: revenue_split.stakers_bps,
+            savings_vault: revenue_split.savings_vault,
+            stakers: revenue_split.stakers,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Splits the accrued author fees of several [Collateral]s according to the [RevenueSplit].
+    ///
+    /// The remaining accounts are
+    /// `[collateral, author_fees, treasury_tokens, insurance_fund_tokens, savings_tokens, stakers_tokens]`
+    /// groups. Recipients with a zero portion may be any account. Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn distribute_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        instructions::distribute_fees::handler(ctx)
+    }
+
+    /// Sets the flash mint ceiling and fee of the [Bank].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_flash_mint_params(
+        ctx: Context<SetFlashMintParams>,
+        flash_mint_ceiling: u64,
+        flash_mint_fee_bps: u16,
+    ) -> Result<()> {
+        invariant!(
+            flash_mint_fee_bps <= BPS_DENOMINATOR,
+            FlashMintInvalidFeeBps
+        );
+        let bank = &mut ctx.accounts.bank;
+        bank.flash_mint_ceiling = flash_mint_ceiling;
+        bank.flash_mint_fee_bps = flash_mint_fee_bps;
+
+        emit!(SetFlashMintParamsEvent {
+            bank: bank.key(),
+            flash_mint_ceiling,
+            flash_mint_fee_bps,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
+
+    /// Injects tokens from the [InsuranceFund] into the backing of a [Collateral]
+    /// after a loss event.
+    ///
+    /// Only the bankman may call this, and only if it is the smart wallet of the
+    /// [Bank::governor] or a [SignerSet], so that every injection is approved by governance.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cover_shortfall(ctx: Context<CoverShortfall>, amount: u64) -> Result<()> {
+        instructions::cover_shortfall::handler(ctx, amount)
+    }
+
+    /// Claims the farming rewards of a [Collateral]'s Arrow and withdraws them
+    /// into a token account owned by the [Bank].
+    ///
+    /// Anyone may call this. The Arrow's beneficiary must be the [Bank].
+    #[access_control(ctx.accounts.validate())]
+    pub fn harvest_rewards(ctx: Context<HarvestRewards>) -> Result<()> {
+        instructions::harvest_rewards::handler(ctx)
+    }
+
+    /// Swaps harvested rewards of the [Bank] into a stablecoin, deposits it into the
+    /// Saber pool of a [Collateral]'s Arrow, and stakes the LP tokens into the Arrow.
+    ///
+    /// The Arrow tokens are added to the backing of $CASH if `to_backing` is set, and are
+    /// otherwise kept by the [Bank]. Fails if the swap returns fewer than
+    /// `min_stablecoin_amount` stablecoins, or if fewer than `min_lp_amount` LP tokens
+    /// are minted.
+    ///
+    /// Only the bankman may call this, since the caller chooses the slippage bound.
+    #[access_control(ctx.accounts.validate())]
+    pub fn swap_rewards_to_backing(
+        ctx: Context<SwapRewardsToBacking>,
+        amount_in: u64,
+        min_stablecoin_amount: u64,
+        min_lp_amount: u64,
+        to_backing: bool,
+    ) -> Result<()> {
+        instructions::swap_rewards_to_backing::handler(
+            ctx,
+            amount_in,
+            min_stablecoin_amount,
+            min_lp_amount,
+            to_backing,
+        )
+    }
+
+    /// Creates a [SignerSet], an M-of-N set of signers which may act as
+    /// the [Bank::bankman] or [Bank::curator].
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_signer_set(
+        ctx: Context<NewSignerSet>,
+        max_owners: u8,
+        threshold: u64,
+        owners: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::new_signer_set::handler(ctx, max_owners, threshold, owners)
+    }
+
+    /// Sets the owners and threshold of a [SignerSet], invalidating its pending proposals.
+    ///
+    /// Must be signed by the [SignerSet] itself via an executed [Proposal].
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_signer_set_owners(
+        ctx: Context<SetSignerSetOwners>,
+        threshold: u64,
+        owners: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_signer_set_owners::handler(ctx, threshold, owners)
+    }
+
+    /// Proposes an instruction to be signed by a [SignerSet].
+    ///
+    /// Only the instructions in [PROPOSAL_ALLOWED_INSTRUCTIONS] may be proposed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        instruction: ProposalInstruction,
+    ) -> Result<()> {
+        instructions::create_proposal::handler(ctx, instruction)
+    }
+
+    /// Approves a [Proposal] as an owner of its [SignerSet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        instructions::approve_proposal::handler(ctx)
+    }
+
+    /// Executes a [Proposal] which has been approved by [SignerSet::threshold] owners,
+    /// signing its instruction as the [SignerSet].
+    #[access_control(ctx.accounts.validate())]
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_proposal::handler(ctx)
+    }
+
     /// Withdraws the author fee to the specified location.
     #[access_control(ctx.accounts.validate())]
     pub fn withdraw_author_fee(ctx: Context<WithdrawAuthorFee>, amount: u64) -> Result<()> {
         instructions::withdraw_author_fee::handler(ctx, amount)
     }
+
+    /// Withdraws the author fees of several [Collateral]s at once.
+    ///
+    /// The remaining accounts are `[collateral, author_fees, destination]` groups.
+    /// If `amounts` is not provided, the full balance of each author fee account is withdrawn.
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw_author_fees<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawAuthorFees<'info>>,
+        amounts: Option<Vec<u64>>,
+    ) -> Result<()> {
+        instructions::withdraw_author_fees::handler(ctx, amounts)
+    }
 }
 
 /// Accounts for [bankman::new_bank].
@@ -187,18 +726,18 @@ pub struct NewBank<'info> {
     pub crate_token: SystemAccount<'info>,
 
     /// The `brrr_issue_authority`.
-    /// CHECK: Arbitrary.
+    /// CHECK: Checked against [addresses::find_issue_authority_address].
     pub brrr_issue_authority: UncheckedAccount<'info>,
 
     /// The `burn_withdraw_authority`.
-    /// CHECK: Arbitrary.
+    /// CHECK: Checked against [addresses::find_withdraw_authority_address].
     pub burn_withdraw_authority: UncheckedAccount<'info>,
 
     /// Payer of the crate initialization.
     #[account(mut)]
     pub payer: Signer<'info>,
 
-    /// The admin, who becomes the curator and the curator setter.
+    /// The admin, who initially holds every role of the [Bank].
     /// CHECK: Arbitrary.
     pub admin: UncheckedAccount<'info>,
 
@@ -243,18 +782,83 @@ pub struct AuthorizeCollateral<'info> {
     pub system_program: Program<'info, System>,
 }
 
-/// Accounts for [bankman::set_collateral_hard_cap].
+/// Accounts for [bankman::new_collateral_metadata].
 #[derive(Accounts)]
-pub struct SetCollateralHardCap<'info> {
+pub struct NewCollateralMetadata<'info> {
     /// The [Bank].
     pub bank: Account<'info, Bank>,
     /// The [Collateral].
+    pub collateral: Account<'info, Collateral>,
+
+    /// The [CollateralMetadata] to create.
+    #[account(
+        init,
+        seeds = [
+            b"CollateralMetadata".as_ref(),
+            collateral.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + CollateralMetadata::BYTES,
+        payer = payer
+    )]
+    pub metadata: Account<'info, CollateralMetadata>,
+
+    /// The [Bank::curator].
+    pub curator: Signer<'info>,
+
+    /// Payer of the metadata initialization.
     #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [bankman::set_collateral_metadata].
+#[derive(Accounts)]
+pub struct SetCollateralMetadata<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Collateral].
     pub collateral: Account<'info, Collateral>,
+    /// The [CollateralMetadata].
+    #[account(mut, has_one = collateral)]
+    pub metadata: Account<'info, CollateralMetadata>,
     /// The [Bank::curator].
     pub curator: Signer<'info>,
 }
 
+/// Accounts for [bankman::set_collateral_hard_cap].
+#[derive(Accounts)]
+pub struct SetCollateralHardCap<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Collateral].
+    #[account(mut)]
+    pub collateral: Account<'info, Collateral>,
+    /// The [Bank::risk_manager].
+    pub risk_manager: Signer<'info>,
+}
+
+/// Accounts for [bankman::set_collateral_reserve].
+#[derive(Accounts)]
+pub struct SetCollateralReserve<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Collateral].
+    #[account(mut)]
+    pub collateral: Account<'info, Collateral>,
+    /// The [Bank::risk_manager].
+    pub risk_manager: Signer<'info>,
+    /// The reserve account of the lending protocol issuing the collateral.
+    /// CHECK: must be owned by the lending program, and is parsed as a [ReserveState].
+    pub reserve: UncheckedAccount<'info>,
+    /// The lending program which owns the reserve.
+    /// CHECK: Arbitrary executable account.
+    #[account(executable)]
+    pub lending_program: UncheckedAccount<'info>,
+}
+
 /// Accounts for [bankman::set_curator].
 #[derive(Accounts)]
 pub struct SetCurator<'info> {
@@ -281,6 +885,95 @@ pub struct SetBankman<'info> {
     pub next_bankman: UncheckedAccount<'info>,
 }
 
+/// Accounts for [bankman::set_governor].
+#[derive(Accounts)]
+pub struct SetGovernor<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The governor.
+    /// CHECK: Checked to be a governor of the Tribeca govern program.
+    pub governor: UncheckedAccount<'info>,
+    /// The smart wallet of the governor, which becomes the [Bank::bankman].
+    /// CHECK: Checked against the smart wallet recorded in the governor.
+    pub smart_wallet: UncheckedAccount<'info>,
+}
+
+/// Accounts for [bankman::set_pending_treasury].
+#[derive(Accounts)]
+pub struct SetPendingTreasury<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The [Bank::treasury] to set.
+    /// CHECK: Arbitrary.
+    pub next_treasury: UncheckedAccount<'info>,
+}
+
+/// Accounts for [bankman::apply_pending_treasury].
+#[derive(Accounts)]
+pub struct ApplyPendingTreasury<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+}
+
+/// Accounts for [bankman::set_compound_fees].
+#[derive(Accounts)]
+pub struct SetCompoundFees<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::fee_manager].
+    pub fee_manager: Signer<'info>,
+}
+
+/// Accounts for [bankman::set_pending_revenue_split].
+#[derive(Accounts)]
+pub struct SetPendingRevenueSplit<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [RevenueSplit].
+    #[account(mut, has_one = bank)]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    /// The [Bank::fee_manager].
+    pub fee_manager: Signer<'info>,
+    /// The [RevenueSplit::savings_vault] to set.
+    /// CHECK: must be the savings vault of the [Bank].
+    pub savings_vault: UncheckedAccount<'info>,
+    /// The [RevenueSplit::stakers] to set.
+    /// CHECK: Arbitrary.
+    pub stakers: UncheckedAccount<'info>,
+}
+
+/// Accounts for [bankman::apply_pending_revenue_split].
+#[derive(Accounts)]
+pub struct ApplyPendingRevenueSplit<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [RevenueSplit].
+    #[account(mut, has_one = bank)]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    /// The [Bank::fee_manager].
+    pub fee_manager: Signer<'info>,
+}
+
+/// Accounts for [bankman::set_flash_mint_params].
+#[derive(Accounts)]
+pub struct SetFlashMintParams<'info> {
+    /// The [Bank].
+    #[account(mut)]
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::risk_manager].
+    pub risk_manager: Signer<'info>,
+}
+
 /// Errors.
 #[error_code]
 pub enum ErrorCode {
@@ -288,6 +981,14 @@ pub enum ErrorCode {
     UnauthorizedNotCurator,
     #[msg("Must be the bankman.")]
     UnauthorizedNotBankman,
+    #[msg("Must be the risk manager.")]
+    UnauthorizedNotRiskManager,
+    #[msg("Must be the fee manager.")]
+    UnauthorizedNotFeeManager,
+    #[msg("Must be the guardian or the bankman.")]
+    UnauthorizedNotGuardian,
+    #[msg("Must be the pending holder of the role.")]
+    UnauthorizedNotPendingRole,
 
     #[msg("Pool not found in snapshot.", offset = 10)]
     PoolNotFoundInSnapshot,
@@ -300,4 +1001,113 @@ pub enum ErrorCode {
     NewBankWrongDecimals,
     #[msg("new_bank: crate already initialized")]
     NewBankAlreadyInitialized,
+
+    #[msg(
+        "withdraw_author_fees: remaining accounts must be [collateral, author_fees, destination] groups",
+        offset = 30
+    )]
+    WithdrawAuthorFeesInvalidAccounts,
+    #[msg("withdraw_author_fees: number of amounts must match number of collaterals")]
+    WithdrawAuthorFeesAmountsMismatch,
+    #[msg("withdraw_author_fee: destination must be owned by the treasury")]
+    WithdrawAuthorFeeDestinationNotTreasury,
+
+    #[msg("treasury: no treasury is pending", offset = 40)]
+    NoPendingTreasury,
+    #[msg("treasury: timelock has not elapsed")]
+    TreasuryTimelockNotElapsed,
+
+    #[msg(
+        "compound_fees: fee compounding is disabled for this bank",
+        offset = 50
+    )]
+    CompoundFeesDisabled,
+
+    #[msg(
+        "write_down_collateral: collateral must be retired (hard cap of zero)",
+        offset = 60
+    )]
+    WriteDownCollateralNotRetired,
+    #[msg("write_down_collateral: amount exceeds the value of the backing")]
+    WriteDownCollateralExceedsBacking,
+
+    #[msg("insurance fund: the bank has no insurance fund", offset = 70)]
+    InsuranceFundNotCreated,
+    #[msg("cover_shortfall: the bankman must be a governor's smart wallet or a signer set")]
+    CoverShortfallNotGoverned,
+
+    #[msg(
+        "collateral: collateral kind does not support this operation",
+        offset = 80
+    )]
+    CollateralKindMismatch,
+
+    #[msg("flash mint: fee bps must not exceed 10000", offset = 90)]
+    FlashMintInvalidFeeBps,
+
+    #[msg(
+        "harvest_rewards: the bank must be the beneficiary of the arrow",
+        offset = 100
+    )]
+    HarvestRewardsNotBeneficiary,
+    #[msg("swap_rewards_to_backing: the LP pool must hold the stablecoin and quote tokens")]
+    SwapRewardsTokenNotInPool,
+
+    #[msg("revenue split: bps must sum to 10000", offset = 110)]
+    RevenueSplitInvalidBps,
+    #[msg("revenue split: savings vault must be the savings vault of the bank")]
+    RevenueSplitInvalidSavingsVault,
+    #[msg("revenue split: no split is pending")]
+    NoPendingRevenueSplit,
+    #[msg("revenue split: timelock has not elapsed")]
+    RevenueSplitTimelockNotElapsed,
+    #[msg(
+        "distribute_fees: remaining accounts must be [collateral, author_fees, treasury_tokens, insurance_fund_tokens, savings_tokens, stakers_tokens] groups"
+    )]
+    DistributeFeesInvalidAccounts,
+
+    #[msg(
+        "signer set: threshold must be between 1 and the number of owners",
+        offset = 120
+    )]
+    SignerSetInvalidThreshold,
+    #[msg("signer set: too many owners")]
+    SignerSetTooManyOwners,
+    #[msg("signer set: owners must be unique")]
+    SignerSetDuplicateOwners,
+    #[msg("signer set: must be an owner")]
+    NotSignerSetOwner,
+    #[msg("proposal: instruction may not be signed by a signer set")]
+    ProposalInstructionNotAllowed,
+    #[msg("proposal: already executed")]
+    ProposalAlreadyExecuted,
+    #[msg("proposal: not enough approvals")]
+    ProposalThresholdNotMet,
+    #[msg("proposal: the owners of the signer set have changed")]
+    ProposalOwnerSetChanged,
+
+    #[msg("governor: must be owned by the Tribeca govern program", offset = 130)]
+    GovernorNotTrusted,
+    #[msg("governor: smart wallet must be the smart wallet of the governor")]
+    GovernorSmartWalletMismatch,
+    #[msg("governor: account is not a governor")]
+    GovernorInvalid,
+
+    #[msg("migrate: account is not of the expected type", offset = 140)]
+    MigrateInvalidAccount,
+    #[msg("migrate: account is already at the current version")]
+    MigrateAlreadyCurrent,
+
+    #[msg("collateral metadata: name is too long", offset = 150)]
+    CollateralMetadataNameTooLong,
+    #[msg("collateral metadata: too many underlying mints")]
+    CollateralMetadataTooManyMints,
+
+    #[msg("reserve: account is not a valid lending reserve", offset = 160)]
+    InvalidReserve,
+    #[msg("reserve: must be refreshed in the current slot")]
+    ReserveStale,
+
+    #[msg("pricing: accounts do not match the collateral kind", offset = 170)]
+    InvalidPricingAccounts,
 }
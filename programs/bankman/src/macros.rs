@@ -0,0 +1,19 @@
+/// Generates the signer seeds for a [crate::Bank].
+#[macro_export]
+macro_rules! gen_bank_signer_seeds {
+    ($bank:expr) => {
+        &[b"Bank".as_ref(), $bank.crate_token.as_ref(), &[$bank.bump]]
+    };
+}
+
+/// Generates the signer seeds for a [crate::SignerSet].
+#[macro_export]
+macro_rules! gen_signer_set_signer_seeds {
+    ($signer_set:expr) => {
+        &[
+            b"SignerSet".as_ref(),
+            $signer_set.base.as_ref(),
+            &[$signer_set.bump],
+        ]
+    };
+}
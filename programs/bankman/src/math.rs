@@ -0,0 +1,27 @@
+//! Math helpers
+
+/// Number of basis points in 100%.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Computes `bps` basis points of `amount`, rounding down.
+pub fn apply_bps(amount: u64, bps: u16) -> Option<u64> {
+    u64::try_from(
+        u128::from(amount)
+            .checked_mul(bps.into())?
+            .checked_div(BPS_DENOMINATOR.into())?,
+    )
+    .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_bps() {
+        assert_eq!(apply_bps(1_000_000, 50), Some(5_000));
+        assert_eq!(apply_bps(199, 50), Some(0));
+        assert_eq!(apply_bps(u64::MAX, BPS_DENOMINATOR), Some(u64::MAX));
+        assert_eq!(apply_bps(u64::MAX, 0), Some(0));
+    }
+}
@@ -0,0 +1,67 @@
+//! Collateral pricing
+//!
+//! bankman values collateral in $CASH when compounding fees and writing down collateral.
+//! The accounts needed to price a [Collateral] are passed as remaining accounts,
+//! depending on its [CollateralKind]:
+//! - [CollateralKind::SaberLpArrow]: `[arrow, saber_swap, pool_mint, reserve_a, reserve_b]`.
+//! - [CollateralKind::Stablecoin]: none.
+//! - [CollateralKind::YieldBearing]: `[reserve]`, refreshed in the current slot.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use arrow_sunny::Arrow;
+use converter::{CashStablecoin, CashSwap, CollateralPricer, Rounding, SaberSwap};
+use stable_swap_anchor::SwapInfo;
+use vipers::{assert_keys_eq, unwrap_int};
+
+use crate::{Collateral, CollateralKind, ReserveState};
+
+/// Values `collateral_amount` tokens of the [Collateral] in $CASH at its nominal price,
+/// rounding down.
+pub(crate) fn cash_value<'info>(
+    collateral: &Collateral,
+    collateral_mint: &Account<'info, Mint>,
+    collateral_amount: u64,
+    pricing: &[AccountInfo<'info>],
+) -> Result<u64> {
+    assert_keys_eq!(collateral_mint.key(), collateral.mint);
+    let value = match (collateral.kind, pricing) {
+        (CollateralKind::SaberLpArrow, [arrow, saber_swap, pool_mint, reserve_a, reserve_b]) => {
+            let arrow: Account<Arrow> = Account::try_from(arrow)?;
+            let saber_swap: Account<SwapInfo> = Account::try_from(saber_swap)?;
+            let pool_mint: Account<Mint> = Account::try_from(pool_mint)?;
+            let reserve_a: Account<TokenAccount> = Account::try_from(reserve_a)?;
+            let reserve_b: Account<TokenAccount> = Account::try_from(reserve_b)?;
+            assert_keys_eq!(arrow.mint, collateral.mint);
+            assert_keys_eq!(arrow.vendor_miner.mint, pool_mint);
+            assert_keys_eq!(saber_swap.pool_mint, pool_mint);
+            assert_keys_eq!(saber_swap.token_a.reserves, reserve_a);
+            assert_keys_eq!(saber_swap.token_b.reserves, reserve_b);
+
+            CashSwap {
+                lp_mint_decimals: pool_mint.decimals,
+                saber: SaberSwap {
+                    initial_amp_factor: saber_swap.initial_amp_factor,
+                    target_amp_factor: saber_swap.target_amp_factor,
+                    current_ts: Clock::get()?.unix_timestamp,
+                    start_ramp_ts: saber_swap.start_ramp_ts,
+                    stop_ramp_ts: saber_swap.stop_ramp_ts,
+
+                    lp_mint_supply: pool_mint.supply,
+                    token_a_reserve: reserve_a.amount,
+                    token_b_reserve: reserve_b.amount,
+                },
+            }
+            .cash_for_collateral(collateral_amount, Rounding::Down)
+        }
+        (CollateralKind::Stablecoin, []) => CashStablecoin {
+            mint_decimals: collateral_mint.decimals,
+        }
+        .cash_for_collateral(collateral_amount, Rounding::Down),
+        (CollateralKind::YieldBearing, [reserve]) => ReserveState::load(collateral, reserve)?
+            .pricer()?
+            .cash_for_collateral(collateral_amount, Rounding::Down),
+        _ => return Err(error!(crate::ErrorCode::InvalidPricingAccounts)),
+    };
+    Ok(unwrap_int!(value))
+}
@@ -0,0 +1,203 @@
+//! Lending reserves
+//!
+//! Yield-bearing collateral is valued using the reserve account of the lending protocol
+//! which issues it. Reserves follow the layout of an
+//! [SPL token-lending](https://github.com/solana-labs/solana-program-library/tree/master/token-lending)
+//! `Reserve`; only the fields needed to compute the exchange rate are read.
+
+use std::convert::TryInto;
+
+use anchor_lang::prelude::*;
+use converter::{YieldBearingToken, WAD};
+use vipers::{assert_keys_eq, invariant, unwrap_int};
+
+use crate::Collateral;
+
+/// Supported version of the reserve layout.
+pub const RESERVE_VERSION: u8 = 1;
+/// Length of a reserve account.
+pub const RESERVE_LEN: usize = 571;
+
+const VERSION_OFFSET: usize = 0;
+const LAST_UPDATE_SLOT_OFFSET: usize = 1;
+const LAST_UPDATE_STALE_OFFSET: usize = 9;
+const LIQUIDITY_MINT_DECIMALS_OFFSET: usize = 74;
+const LIQUIDITY_AVAILABLE_AMOUNT_OFFSET: usize = 171;
+const LIQUIDITY_BORROWED_AMOUNT_WADS_OFFSET: usize = 179;
+const COLLATERAL_MINT_OFFSET: usize = 227;
+const COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET: usize = 259;
+
+/// The fields of a reserve which determine the exchange rate of its collateral token.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReserveState {
+    /// Slot at which the reserve was last refreshed.
+    pub last_update_slot: u64,
+    /// True if the reserve must be refreshed before its exchange rate can be used.
+    pub stale: bool,
+    /// Decimals of the underlying liquidity mint.
+    pub liquidity_mint_decimals: u8,
+    /// Amount of liquidity available to be borrowed.
+    pub available_amount: u64,
+    /// Amount of liquidity borrowed, plus interest, as a wad.
+    pub borrowed_amount_wads: u128,
+    /// Mint of the collateral (yield-bearing) token.
+    pub collateral_mint: Pubkey,
+    /// Total supply of the collateral token.
+    pub collateral_mint_total_supply: u64,
+}
+
+impl ReserveState {
+    /// Reads the reserve state from the data of a reserve account.
+    pub fn unpack(data: &[u8]) -> Result<Self> {
+        invariant!(data.len() == RESERVE_LEN, InvalidReserve);
+        invariant!(data[VERSION_OFFSET] == RESERVE_VERSION, InvalidReserve);
+        Ok(Self {
+            last_update_slot: u64::from_le_bytes(read(data, LAST_UPDATE_SLOT_OFFSET)?),
+            stale: data[LAST_UPDATE_STALE_OFFSET] != 0,
+            liquidity_mint_decimals: data[LIQUIDITY_MINT_DECIMALS_OFFSET],
+            available_amount: u64::from_le_bytes(read(data, LIQUIDITY_AVAILABLE_AMOUNT_OFFSET)?),
+            borrowed_amount_wads: u128::from_le_bytes(read(
+                data,
+                LIQUIDITY_BORROWED_AMOUNT_WADS_OFFSET,
+            )?),
+            collateral_mint: Pubkey::new_from_array(read(data, COLLATERAL_MINT_OFFSET)?),
+            collateral_mint_total_supply: u64::from_le_bytes(read(
+                data,
+                COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET,
+            )?),
+        })
+    }
+
+    /// Reads the reserve state of a [Collateral], checking that the reserve account
+    /// is the one recorded on the [Collateral] and is owned by its lending program.
+    pub fn load(collateral: &Collateral, reserve: &AccountInfo) -> Result<Self> {
+        assert_keys_eq!(collateral.reserve, reserve.key(), InvalidReserve);
+        assert_keys_eq!(*reserve.owner, collateral.lending_program, InvalidReserve);
+        let state = Self::unpack(&reserve.try_borrow_data()?)?;
+        assert_keys_eq!(state.collateral_mint, collateral.mint, InvalidReserve);
+        Ok(state)
+    }
+
+    /// Writes the reserve state into the data of a reserve account.
+    /// All other fields are left untouched.
+    #[cfg(test)]
+    pub fn pack_into(&self, data: &mut [u8]) -> Result<()> {
+        invariant!(data.len() == RESERVE_LEN, InvalidReserve);
+        data[VERSION_OFFSET] = RESERVE_VERSION;
+        write(
+            data,
+            LAST_UPDATE_SLOT_OFFSET,
+            &self.last_update_slot.to_le_bytes(),
+        );
+        data[LAST_UPDATE_STALE_OFFSET] = self.stale.into();
+        data[LIQUIDITY_MINT_DECIMALS_OFFSET] = self.liquidity_mint_decimals;
+        write(
+            data,
+            LIQUIDITY_AVAILABLE_AMOUNT_OFFSET,
+            &self.available_amount.to_le_bytes(),
+        );
+        write(
+            data,
+            LIQUIDITY_BORROWED_AMOUNT_WADS_OFFSET,
+            &self.borrowed_amount_wads.to_le_bytes(),
+        );
+        write(data, COLLATERAL_MINT_OFFSET, self.collateral_mint.as_ref());
+        write(
+            data,
+            COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET,
+            &self.collateral_mint_total_supply.to_le_bytes(),
+        );
+        Ok(())
+    }
+
+    /// Total liquidity owed to holders of the collateral token, as a wad.
+    pub fn total_liquidity_wads(&self) -> Option<u128> {
+        u128::from(self.available_amount)
+            .checked_mul(WAD.into())?
+            .checked_add(self.borrowed_amount_wads)
+    }
+
+    /// Prices the collateral token at the exchange rate of the reserve.
+    /// The reserve must have been refreshed in the current slot.
+    pub fn pricer(&self) -> Result<YieldBearingToken> {
+        require!(
+            !self.stale && self.last_update_slot == Clock::get()?.slot,
+            ReserveStale
+        );
+        Ok(YieldBearingToken {
+            total_liquidity_wads: unwrap_int!(self.total_liquidity_wads()),
+            collateral_supply: self.collateral_mint_total_supply,
+            liquidity_decimals: self.liquidity_mint_decimals,
+        })
+    }
+}
+
+fn read<const N: usize>(data: &[u8], offset: usize) -> Result<[u8; N]> {
+    let end = unwrap_int!(offset.checked_add(N));
+    Ok(unwrap_int!(data
+        .get(offset..end)
+        .and_then(|s| s.try_into().ok())))
+}
+
+#[cfg(test)]
+fn write(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_roundtrip() {
+        let state = ReserveState {
+            last_update_slot: 42,
+            stale: false,
+            liquidity_mint_decimals: 6,
+            available_amount: 1_000,
+            borrowed_amount_wads: 20u128 * u128::from(WAD),
+            collateral_mint: Pubkey::new_unique(),
+            collateral_mint_total_supply: 1_000,
+        };
+        let mut data = vec![0u8; RESERVE_LEN];
+        state.pack_into(&mut data).unwrap();
+        assert_eq!(ReserveState::unpack(&data).unwrap(), state);
+        assert_eq!(
+            state.total_liquidity_wads(),
+            Some(1_020u128 * u128::from(WAD))
+        );
+    }
+
+    #[test]
+    fn test_reserve_load_checks_owner() {
+        let state = ReserveState {
+            collateral_mint: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        let mut data = vec![0u8; RESERVE_LEN];
+        state.pack_into(&mut data).unwrap();
+
+        let (key, lending_program) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut lamports = 0;
+        let reserve = AccountInfo::new(
+            &key,
+            false,
+            false,
+            &mut lamports,
+            &mut data,
+            &lending_program,
+            false,
+            0,
+        );
+        let mut collateral = Collateral {
+            mint: state.collateral_mint,
+            reserve: key,
+            lending_program,
+            ..Default::default()
+        };
+        assert_eq!(ReserveState::load(&collateral, &reserve).unwrap(), state);
+
+        collateral.lending_program = Pubkey::new_unique();
+        assert!(ReserveState::load(&collateral, &reserve).is_err());
+    }
+}
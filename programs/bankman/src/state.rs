@@ -17,10 +17,127 @@ pub struct Bank {
     pub curator: Pubkey,
     /// Account that can change who the curator is.
     pub bankman: Pubkey,
+
+    /// Owner of the token accounts that author fees may be withdrawn to.
+    pub treasury: Pubkey,
+    /// Treasury that will replace the [Bank::treasury] once the timelock has elapsed.
+    pub pending_treasury: Pubkey,
+    /// Time at which the [Bank::pending_treasury] may be applied.
+    /// Zero if there is no pending treasury.
+    pub pending_treasury_eta: i64,
+
+    /// If true, anyone may deposit the accrued author fees back into the backing of $CASH.
+    pub compound_fees: bool,
+
+    /// Amount of $CASH which is not backed by any collateral.
+    /// This is covered by the [Bank::surplus] and by burning $CASH from the surplus buffer.
+    pub bad_debt: u64,
+
+    /// The [InsuranceFund], or the default [Pubkey] if none has been created.
+    pub insurance_fund: Pubkey,
+
+    /// Maximum amount of $CASH that may be flash minted at once.
+    /// Zero disables flash minting.
+    pub flash_mint_ceiling: u64,
+    /// Fee on flash minted $CASH, in bps. The fee is burned along with the principal.
+    pub flash_mint_fee_bps: u16,
+
+    /// Account that can change collateral hard caps and the flash mint ceiling.
+    pub risk_manager: Pubkey,
+    /// Account that may accept the [Bank::risk_manager] role.
+    pub pending_risk_manager: Pubkey,
+    /// Account that can change fee parameters and withdraw fees.
+    pub fee_manager: Pubkey,
+    /// Account that may accept the [Bank::fee_manager] role.
+    pub pending_fee_manager: Pubkey,
+    /// Account that can pause the [Bank].
+    pub guardian: Pubkey,
+    /// Account that may accept the [Bank::guardian] role.
+    pub pending_guardian: Pubkey,
+    /// If true, $CASH may not be printed, burned, or flash minted.
+    pub paused: bool,
+
+    /// The governor whose smart wallet is the [Bank::bankman],
+    /// or the default [Pubkey] if the [Bank] is not governed.
+    pub governor: Pubkey,
+
+    /// Layout version of the [Bank]. Zero for accounts created before versioning.
+    pub version: u8,
+    /// Value of the backing in excess of the $CASH printed, in $CASH, funded by
+    /// compounded fees. This is used to cover the [Bank::bad_debt].
+    pub surplus: u64,
+    /// Reserved for future fields, which are carved out of this space.
+    pub reserved: [u64; 31],
 }
 
 impl Bank {
-    pub const BYTES: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES * 3;
+    pub const BYTES: usize = PUBKEY_BYTES
+        + 1
+        + PUBKEY_BYTES * 3
+        + PUBKEY_BYTES * 2
+        + 8
+        + 1
+        + 8
+        + PUBKEY_BYTES
+        + 8
+        + 2
+        + PUBKEY_BYTES * 6
+        + 1
+        + PUBKEY_BYTES
+        + 1
+        + 8
+        + 8 * 31;
+
+    /// Current layout version of the [Bank].
+    pub const VERSION: u8 = 1;
+
+    /// Upgrades a [Bank] created by a previous version of the program
+    /// to [Bank::VERSION], filling in the fields that have been added since.
+    pub fn migrate(&mut self) {
+        if self.version < 1 {
+            // roles added after the bank was created default to the bankman
+            for role in [
+                &mut self.treasury,
+                &mut self.risk_manager,
+                &mut self.fee_manager,
+                &mut self.guardian,
+            ] {
+                if *role == Pubkey::default() {
+                    *role = self.bankman;
+                }
+            }
+        }
+        self.version = Self::VERSION;
+    }
+
+    /// Covers as much of the [Bank::bad_debt] as possible from the [Bank::surplus],
+    /// returning the amount covered.
+    pub fn cover_bad_debt_from_surplus(&mut self) -> u64 {
+        let amount = self.bad_debt.min(self.surplus);
+        self.bad_debt -= amount;
+        self.surplus -= amount;
+        amount
+    }
+
+    /// Returns the current and pending holders of a [BankRole].
+    pub fn role_mut(&mut self, role: BankRole) -> (&mut Pubkey, &mut Pubkey) {
+        match role {
+            BankRole::RiskManager => (&mut self.risk_manager, &mut self.pending_risk_manager),
+            BankRole::FeeManager => (&mut self.fee_manager, &mut self.pending_fee_manager),
+            BankRole::Guardian => (&mut self.guardian, &mut self.pending_guardian),
+        }
+    }
+}
+
+/// A role of a [Bank] which is appointed by the [Bank::bankman] and accepted by its holder.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BankRole {
+    /// The [Bank::risk_manager].
+    RiskManager,
+    /// The [Bank::fee_manager].
+    FeeManager,
+    /// The [Bank::guardian].
+    Guardian,
 }
 
 /// The collateral which has been authorized to mint $CASH.
@@ -35,8 +152,419 @@ pub struct Collateral {
     pub bump: u8,
     /// Hard cap on the number of collateral tokens that can be issued from this pool.
     pub hard_cap: u64,
+    /// Total value, in $CASH, that has been written down from this collateral.
+    pub written_down: u64,
+    /// How the collateral is valued.
+    pub kind: CollateralKind,
+    /// The reserve account which values a [CollateralKind::YieldBearing] collateral.
+    /// Unused for other kinds of collateral.
+    pub reserve: Pubkey,
+
+    /// Layout version of the [Collateral]. Zero for accounts created before versioning.
+    pub version: u8,
+
+    /// Time at which the [Collateral] was authorized.
+    /// Zero if it was authorized before this was recorded.
+    pub authorized_at: i64,
+    /// The [Bank::curator] who authorized the [Collateral].
+    /// The default [Pubkey] if it was authorized before this was recorded.
+    pub authorized_by: Pubkey,
+
+    /// The lending program which must own the [Collateral::reserve].
+    pub lending_program: Pubkey,
+
+    /// Reserved for future fields, which are carved out of this space.
+    pub reserved: [u64; 7],
 }
 
 impl Collateral {
-    pub const BYTES: usize = PUBKEY_BYTES * 2 + 1 + 8;
+    pub const BYTES: usize =
+        PUBKEY_BYTES * 2 + 1 + 8 + 8 + 1 + PUBKEY_BYTES + 1 + 8 + PUBKEY_BYTES * 2 + 8 * 7;
+
+    /// Current layout version of the [Collateral].
+    pub const VERSION: u8 = 2;
+
+    /// Upgrades a [Collateral] created by a previous version of the program
+    /// to [Collateral::VERSION], filling in the fields that have been added since.
+    pub fn migrate(&mut self) {
+        // every field added so far defaults to zero, as the
+        // authorization of older collateral was not recorded
+        self.version = Self::VERSION;
+    }
+}
+
+/// The type of a [Collateral], which determines how it is valued against $CASH.
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum CollateralKind {
+    /// [Arrow](https://arrowprotocol.com)-wrapped [Saber](https://saber.so) LP tokens,
+    /// valued at the LP's virtual price.
+    #[default]
+    SaberLpArrow,
+    /// A plain SPL stablecoin, valued 1:1 with $CASH after decimal scaling.
+    Stablecoin,
+    /// A yield-bearing token of a lending protocol, valued at the exchange rate
+    /// of its [Collateral::reserve].
+    YieldBearing,
+}
+
+/// Display metadata of a [Collateral], maintained by the [Bank::curator].
+#[account]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct CollateralMetadata {
+    /// The [Collateral].
+    pub collateral: Pubkey,
+    /// The bump.
+    pub bump: u8,
+
+    /// Human-readable name of the collateral, at most [CollateralMetadata::MAX_NAME_LEN] bytes.
+    pub name: String,
+    /// Mints of the tokens underlying the collateral, e.g. the reserves of its Saber pool.
+    pub underlying_mints: Vec<Pubkey>,
+    /// The Saber swap of the collateral's LP, or the default [Pubkey] if there is none.
+    pub saber_swap: Pubkey,
+    /// Risk tier assigned by the curator. Lower tiers are considered safer.
+    pub risk_tier: u8,
+}
+
+impl CollateralMetadata {
+    /// Maximum length of [CollateralMetadata::name], in bytes.
+    pub const MAX_NAME_LEN: usize = 32;
+    /// Maximum number of [CollateralMetadata::underlying_mints].
+    pub const MAX_UNDERLYING_MINTS: usize = 4;
+
+    pub const BYTES: usize = PUBKEY_BYTES
+        + 1
+        + 4
+        + Self::MAX_NAME_LEN
+        + 4
+        + PUBKEY_BYTES * Self::MAX_UNDERLYING_MINTS
+        + PUBKEY_BYTES
+        + 1;
+}
+
+/// Holds tokens which can be injected into the backing of $CASH after a loss event.
+///
+/// The insurance fund's token accounts are owned by this account. It is funded by its
+/// [RevenueSplit::insurance_fund_bps] portion of the author fees.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct InsuranceFund {
+    /// The [Bank].
+    pub bank: Pubkey,
+    /// The bump.
+    pub bump: u8,
+}
+
+impl InsuranceFund {
+    pub const BYTES: usize = PUBKEY_BYTES + 1;
+}
+
+/// How the author fees of a [Bank] are split by [crate::bankman::distribute_fees].
+///
+/// Each portion is sent to a token account owned by its recipient.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct RevenueSplit {
+    /// The [Bank].
+    pub bank: Pubkey,
+    /// The bump.
+    pub bump: u8,
+
+    /// Portion of fees sent to the [Bank::treasury], in bps.
+    /// The treasury also receives any rounding remainder.
+    pub treasury_bps: u16,
+    /// Portion of fees sent to the [InsuranceFund], in bps.
+    pub insurance_fund_bps: u16,
+    /// Portion of fees sent to the [RevenueSplit::savings_vault], in bps.
+    pub savings_bps: u16,
+    /// Portion of fees sent to the [RevenueSplit::stakers], in bps.
+    pub stakers_bps: u16,
+
+    /// Owner of the token accounts receiving the savings vault's portion.
+    pub savings_vault: Pubkey,
+    /// Owner of the token accounts receiving the stakers' portion.
+    pub stakers: Pubkey,
+
+    /// [RevenueSplit::treasury_bps] to apply once the timelock has elapsed.
+    pub pending_treasury_bps: u16,
+    /// [RevenueSplit::insurance_fund_bps] to apply once the timelock has elapsed.
+    pub pending_insurance_fund_bps: u16,
+    /// [RevenueSplit::savings_bps] to apply once the timelock has elapsed.
+    pub pending_savings_bps: u16,
+    /// [RevenueSplit::stakers_bps] to apply once the timelock has elapsed.
+    pub pending_stakers_bps: u16,
+    /// [RevenueSplit::savings_vault] to apply once the timelock has elapsed.
+    pub pending_savings_vault: Pubkey,
+    /// [RevenueSplit::stakers] to apply once the timelock has elapsed.
+    pub pending_stakers: Pubkey,
+    /// Time at which the pending split may be applied.
+    /// Zero if there is no pending split.
+    pub pending_eta: i64,
+}
+
+impl RevenueSplit {
+    pub const BYTES: usize =
+        PUBKEY_BYTES + 1 + 2 * 4 + PUBKEY_BYTES * 2 + 2 * 4 + PUBKEY_BYTES * 2 + 8;
+}
+
+/// An M-of-N set of signers which may act as the [Bank::bankman] or [Bank::curator].
+///
+/// Instructions are signed by the set via a [Proposal] which has been approved
+/// by [SignerSet::threshold] owners.
+#[account]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SignerSet {
+    /// Base key used to derive the address of the set.
+    pub base: Pubkey,
+    /// The bump.
+    pub bump: u8,
+    /// Number of owners which must approve a [Proposal] for it to be executed.
+    pub threshold: u64,
+    /// Maximum number of owners the account has space for.
+    pub max_owners: u8,
+    /// Owners of the set.
+    pub owners: Vec<Pubkey>,
+    /// Incremented whenever the owners change, invalidating pending proposals.
+    pub owner_set_seqno: u32,
+    /// Number of proposals created.
+    pub num_proposals: u64,
+}
+
+impl SignerSet {
+    /// Number of bytes of a [SignerSet] with space for `max_owners` owners.
+    pub fn space(max_owners: u8) -> usize {
+        PUBKEY_BYTES + 1 + 8 + 1 + 4 + PUBKEY_BYTES * usize::from(max_owners) + 4 + 8
+    }
+
+    /// Finds the index of an owner.
+    pub fn owner_index(&self, owner: Pubkey) -> Option<usize> {
+        self.owners.iter().position(|o| *o == owner)
+    }
+}
+
+/// A [bankman](crate::bankman) instruction to be signed by a [SignerSet].
+#[account]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Proposal {
+    /// The [SignerSet].
+    pub signer_set: Pubkey,
+    /// Index of the proposal within the [SignerSet].
+    pub index: u64,
+    /// The bump.
+    pub bump: u8,
+    /// Owner who created the proposal.
+    pub proposer: Pubkey,
+    /// The [SignerSet::owner_set_seqno] at creation.
+    pub owner_set_seqno: u32,
+    /// The instruction to execute.
+    pub instruction: ProposalInstruction,
+    /// Whether each owner of the [SignerSet] has approved the proposal.
+    pub approvals: Vec<bool>,
+    /// Time at which the proposal was executed, or zero if it has not been executed.
+    pub executed_at: i64,
+}
+
+impl Proposal {
+    /// Number of bytes of a [Proposal] for the given instruction and number of owners.
+    pub fn space(instruction: &ProposalInstruction, num_owners: usize) -> usize {
+        PUBKEY_BYTES + 8 + 1 + PUBKEY_BYTES + 4 + instruction.space() + 4 + num_owners + 8
+    }
+
+    /// Number of owners which have approved the proposal.
+    pub fn num_approvals(&self) -> u64 {
+        self.approvals.iter().filter(|approved| **approved).count() as u64
+    }
+}
+
+/// An instruction to the [bankman](crate::bankman) program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProposalInstruction {
+    /// Accounts of the instruction.
+    pub accounts: Vec<ProposalAccountMeta>,
+    /// Data of the instruction.
+    pub data: Vec<u8>,
+}
+
+impl ProposalInstruction {
+    fn space(&self) -> usize {
+        4 + self.accounts.len() * ProposalAccountMeta::BYTES + 4 + self.data.len()
+    }
+}
+
+/// An account of a [ProposalInstruction].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProposalAccountMeta {
+    /// Address of the account.
+    pub pubkey: Pubkey,
+    /// True if the account must sign.
+    pub is_signer: bool,
+    /// True if the account is writable.
+    pub is_writable: bool,
+}
+
+impl ProposalAccountMeta {
+    pub const BYTES: usize = PUBKEY_BYTES + 1 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    #[test]
+    fn test_bank_len() {
+        assert_eq!(Bank::BYTES, Bank::default().try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn test_bank_migrate() {
+        let bankman = Pubkey::new_unique();
+        let mut bank = Bank {
+            bankman,
+            fee_manager: Pubkey::new_unique(),
+            ..Default::default()
+        };
+        bank.migrate();
+        assert_eq!(bank.version, Bank::VERSION);
+        assert_eq!(bank.treasury, bankman);
+        assert_eq!(bank.risk_manager, bankman);
+        assert_ne!(bank.fee_manager, bankman);
+        assert_eq!(bank.guardian, bankman);
+    }
+
+    #[test]
+    fn test_cover_bad_debt_from_surplus() {
+        let mut bank = Bank {
+            bad_debt: 100,
+            surplus: 30,
+            ..Default::default()
+        };
+        assert_eq!(bank.cover_bad_debt_from_surplus(), 30);
+        assert_eq!((bank.bad_debt, bank.surplus), (70, 0));
+
+        bank.surplus = 100;
+        assert_eq!(bank.cover_bad_debt_from_surplus(), 70);
+        assert_eq!((bank.bad_debt, bank.surplus), (0, 30));
+        assert_eq!(bank.cover_bad_debt_from_surplus(), 0);
+    }
+
+    /// Zero-extends an account in a previous layout, as `migrate_bank` and
+    /// `migrate_collateral` do, and reads it in the current layout.
+    fn read_extended<T: AccountDeserialize + Discriminator>(
+        legacy: impl AnchorSerialize,
+        bytes: usize,
+    ) -> T {
+        let mut data = T::discriminator().to_vec();
+        data.extend(legacy.try_to_vec().unwrap());
+        assert!(data.len() <= 8 + bytes);
+        data.resize(8 + bytes, 0);
+        T::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_bank_migrate_original_layout() {
+        let (crate_token, crate_mint, curator, bankman) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let mut bank: Bank = read_extended(
+            (crate_token, 254u8, crate_mint, curator, bankman),
+            Bank::BYTES,
+        );
+        assert_eq!(bank.version, 0);
+        bank.migrate();
+
+        assert_eq!(bank.crate_token, crate_token);
+        assert_eq!(bank.bump, 254);
+        assert_eq!(bank.crate_mint, crate_mint);
+        assert_eq!(bank.curator, curator);
+        assert_eq!(bank.bankman, bankman);
+        assert_eq!(bank.treasury, bankman);
+        assert_eq!(bank.fee_manager, bankman);
+        assert_eq!(bank.governor, Pubkey::default());
+        assert!(!bank.paused);
+        assert_eq!(bank.version, Bank::VERSION);
+    }
+
+    #[test]
+    fn test_collateral_migrate_original_layout() {
+        let (bank, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut collateral: Collateral =
+            read_extended((bank, mint, 255u8, 1_000u64), Collateral::BYTES);
+        assert_eq!(collateral.version, 0);
+        collateral.migrate();
+
+        assert_eq!(collateral.bank, bank);
+        assert_eq!(collateral.mint, mint);
+        assert_eq!(collateral.bump, 255);
+        assert_eq!(collateral.hard_cap, 1_000);
+        assert_eq!(collateral.kind, CollateralKind::SaberLpArrow);
+        assert_eq!(collateral.version, Collateral::VERSION);
+    }
+
+    #[test]
+    fn test_collateral_metadata_len() {
+        let metadata = CollateralMetadata {
+            name: "a".repeat(CollateralMetadata::MAX_NAME_LEN),
+            underlying_mints: vec![Pubkey::default(); CollateralMetadata::MAX_UNDERLYING_MINTS],
+            ..Default::default()
+        };
+        assert_eq!(
+            CollateralMetadata::BYTES,
+            metadata.try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_insurance_fund_len() {
+        assert_eq!(
+            InsuranceFund::BYTES,
+            InsuranceFund::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_collateral_len() {
+        assert_eq!(
+            Collateral::BYTES,
+            Collateral::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_revenue_split_len() {
+        assert_eq!(
+            RevenueSplit::BYTES,
+            RevenueSplit::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_signer_set_len() {
+        let signer_set = SignerSet {
+            max_owners: 3,
+            owners: vec![Pubkey::default(); 3],
+            ..Default::default()
+        };
+        assert_eq!(SignerSet::space(3), signer_set.try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn test_proposal_len() {
+        let instruction = ProposalInstruction {
+            accounts: vec![ProposalAccountMeta::default(); 3],
+            data: vec![0; 16],
+        };
+        let proposal = Proposal {
+            instruction: instruction.clone(),
+            approvals: vec![false; 5],
+            ..Default::default()
+        };
+        assert_eq!(
+            Proposal::space(&instruction, 5),
+            proposal.try_to_vec().unwrap().len()
+        );
+    }
 }
@@ -0,0 +1,62 @@
+//! Constant product LP pricing
+
+use crate::pricer::{mul_div, sqrt};
+use crate::{scale_to_cash_decimals, CollateralPricer, Rounding};
+
+/// An LP token of a constant product (`x * y = k`) pool between two stablecoins.
+///
+/// The pool is valued at its fair price of `2 * sqrt(x * y)`, taking each token as worth
+/// 1 $CASH. Unlike the sum of the reserves, this cannot be inflated by trading against the pool.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct ConstantProductLp {
+    /// Total supply of LP tokens.
+    pub lp_mint_supply: u64,
+    /// Amount of token A.
+    pub token_a_reserve: u64,
+    /// Decimals of token A.
+    pub token_a_decimals: u8,
+    /// Amount of token B.
+    pub token_b_reserve: u64,
+    /// Decimals of token B.
+    pub token_b_decimals: u8,
+}
+
+impl ConstantProductLp {
+    /// Calculates the value of the entire pool in $CASH.
+    pub fn calculate_pool_value(&self, rounding: Rounding) -> Option<u128> {
+        let a =
+            scale_to_cash_decimals(self.token_a_reserve.into(), self.token_a_decimals, rounding)?;
+        let b =
+            scale_to_cash_decimals(self.token_b_reserve.into(), self.token_b_decimals, rounding)?;
+        sqrt(a.checked_mul(b)?, rounding)?.checked_mul(2)
+    }
+}
+
+impl CollateralPricer for ConstantProductLp {
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64> {
+        mul_div(
+            self.calculate_pool_value(rounding)?,
+            collateral_amount.into(),
+            self.lp_mint_supply.into(),
+            rounding,
+        )?
+        .try_into()
+        .ok()
+    }
+
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64> {
+        // a higher pool value means fewer LP tokens, so the value is rounded the other way
+        let value_rounding = match rounding {
+            Rounding::Down => Rounding::Up,
+            Rounding::Up => Rounding::Down,
+        };
+        mul_div(
+            cash_amount.into(),
+            self.lp_mint_supply.into(),
+            self.calculate_pool_value(value_rounding)?,
+            rounding,
+        )?
+        .try_into()
+        .ok()
+    }
+}
@@ -1,15 +1,26 @@
-//! Saber swap math helpers for $CASH
+//! Math helpers for converting $CASH to/from collateral tokens
 #![deny(missing_docs)]
 #![deny(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
 #![deny(clippy::integer_arithmetic)]
 
+mod constant_product;
+mod pricer;
+mod stable_swap;
+mod yield_bearing;
+
 use std::cmp::Ordering;
 
 /// Number of decimals of $CASH.
 pub const CASH_DECIMALS: u8 = 6;
 
+pub use constant_product::*;
+pub use pricer::{CollateralPricer, Rounding};
+pub use stable_swap::*;
 pub use stable_swap_math::price::SaberSwap;
+pub use yield_bearing::*;
+
+use stable_swap_math::bn::U192;
 
 /// A Saber swap and number of decimals.
 #[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
@@ -24,39 +35,302 @@ pub struct CashSwap {
 impl CashSwap {
     /// Calculates the virtual price of the given amount of pool tokens.
     pub fn calculate_cash_for_pool_tokens(&self, pool_token_amount: u64) -> Option<u64> {
-        self.scale_lp_to_cash_decimals(
-            self.saber
-                .calculate_virtual_price_of_pool_tokens(pool_token_amount)?,
-        )
+        self.cash_for_collateral(pool_token_amount, Rounding::Down)
     }
 
     /// Calculates the virtual price of the given amount of pool tokens.
     pub fn calculate_pool_tokens_for_cash(&self, cash_amount: u64) -> Option<u64> {
-        self.saber
-            .calculate_pool_tokens_from_virtual_amount(self.scale_cash_to_lp_decimals(cash_amount)?)
-    }
-
-    fn scale_lp_to_cash_decimals(&self, amount: u64) -> Option<u64> {
-        match CASH_DECIMALS.cmp(&self.lp_mint_decimals) {
-            Ordering::Equal => amount.into(),
-            Ordering::Less => amount.checked_mul(
-                10u64.checked_pow(self.lp_mint_decimals.checked_sub(CASH_DECIMALS)?.into())?,
-            ),
-            Ordering::Greater => amount.checked_div(
-                10u64.checked_pow(CASH_DECIMALS.checked_sub(self.lp_mint_decimals)?.into())?,
-            ),
-        }
+        self.collateral_for_cash(cash_amount, Rounding::Down)
     }
+}
 
-    fn scale_cash_to_lp_decimals(&self, amount: u64) -> Option<u64> {
-        match CASH_DECIMALS.cmp(&self.lp_mint_decimals) {
-            Ordering::Equal => amount.into(),
-            Ordering::Less => amount.checked_div(
-                10u64.checked_pow(self.lp_mint_decimals.checked_sub(CASH_DECIMALS)?.into())?,
-            ),
-            Ordering::Greater => amount.checked_mul(
-                10u64.checked_pow(CASH_DECIMALS.checked_sub(self.lp_mint_decimals)?.into())?,
-            ),
+impl CollateralPricer for CashSwap {
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64> {
+        let virtual_amount = mul_div_u192(
+            self.saber.compute_d()?,
+            collateral_amount.into(),
+            self.saber.lp_mint_supply.into(),
+            rounding,
+        )?;
+        scale_to_cash_decimals(virtual_amount.to_u128()?, self.lp_mint_decimals, rounding)?
+            .try_into()
+            .ok()
+    }
+
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64> {
+        let virtual_amount =
+            scale_from_cash_decimals(cash_amount.into(), self.lp_mint_decimals, rounding)?;
+        mul_div_u192(
+            virtual_amount.into(),
+            self.saber.lp_mint_supply.into(),
+            self.saber.compute_d()?,
+            rounding,
+        )?
+        .to_u64()
+    }
+}
+
+/// A plain stablecoin, valued 1:1 with $CASH after decimal scaling.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct CashStablecoin {
+    /// Decimals of the stablecoin mint.
+    pub mint_decimals: u8,
+}
+
+impl CashStablecoin {
+    /// Calculates the amount of $CASH the given amount of stablecoin tokens is worth.
+    pub fn calculate_cash_for_tokens(&self, token_amount: u64) -> Option<u64> {
+        self.cash_for_collateral(token_amount, Rounding::Down)
+    }
+
+    /// Calculates the amount of stablecoin tokens the given amount of $CASH is worth.
+    pub fn calculate_tokens_for_cash(&self, cash_amount: u64) -> Option<u64> {
+        self.collateral_for_cash(cash_amount, Rounding::Down)
+    }
+}
+
+impl CollateralPricer for CashStablecoin {
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64> {
+        scale_to_cash_decimals(collateral_amount.into(), self.mint_decimals, rounding)?
+            .try_into()
+            .ok()
+    }
+
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64> {
+        scale_from_cash_decimals(cash_amount.into(), self.mint_decimals, rounding)?
+            .try_into()
+            .ok()
+    }
+}
+
+/// Scales an amount of a token with `decimals` decimals to $CASH decimals.
+pub(crate) fn scale_to_cash_decimals(
+    amount: u128,
+    decimals: u8,
+    rounding: Rounding,
+) -> Option<u128> {
+    scale_decimals(amount, decimals, CASH_DECIMALS, rounding)
+}
+
+/// Scales an amount of $CASH to a token with `decimals` decimals.
+pub(crate) fn scale_from_cash_decimals(
+    amount: u128,
+    decimals: u8,
+    rounding: Rounding,
+) -> Option<u128> {
+    scale_decimals(amount, CASH_DECIMALS, decimals, rounding)
+}
+
+fn scale_decimals(amount: u128, from: u8, to: u8, rounding: Rounding) -> Option<u128> {
+    match from.cmp(&to) {
+        Ordering::Equal => amount.into(),
+        Ordering::Less => amount.checked_mul(10u128.checked_pow(to.checked_sub(from)?.into())?),
+        Ordering::Greater => pricer::mul_div(
+            amount,
+            1,
+            10u128.checked_pow(from.checked_sub(to)?.into())?,
+            rounding,
+        ),
+    }
+}
+
+/// Computes `a * b / c` with the given [Rounding] using 192-bit integers.
+pub(crate) fn mul_div_u192(a: U192, b: U192, c: U192, rounding: Rounding) -> Option<U192> {
+    let product = a.checked_mul(b)?;
+    let quotient = product.checked_div(c)?;
+    match rounding {
+        Rounding::Down => Some(quotient),
+        Rounding::Up => {
+            if product.checked_rem(c)?.is_zero() {
+                Some(quotient)
+            } else {
+                quotient.checked_add(1.into())
+            }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stablecoin_scaling() {
+        let usdc = CashStablecoin { mint_decimals: 6 };
+        assert_eq!(usdc.calculate_cash_for_tokens(1_000_000), Some(1_000_000));
+        assert_eq!(usdc.calculate_tokens_for_cash(1_000_000), Some(1_000_000));
+
+        let nine = CashStablecoin { mint_decimals: 9 };
+        assert_eq!(
+            nine.calculate_cash_for_tokens(1_000_000_999),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            nine.cash_for_collateral(1_000_000_999, Rounding::Up),
+            Some(1_000_001)
+        );
+        assert_eq!(
+            nine.calculate_tokens_for_cash(1_000_000),
+            Some(1_000_000_000)
+        );
+
+        let two = CashStablecoin { mint_decimals: 2 };
+        assert_eq!(two.calculate_cash_for_tokens(100), Some(1_000_000));
+        assert_eq!(two.calculate_tokens_for_cash(1_009_999), Some(100));
+        assert_eq!(two.collateral_for_cash(1_009_999, Rounding::Up), Some(101));
+    }
+
+    #[test]
+    fn test_constant_product_fair_value() {
+        let lp = ConstantProductLp {
+            lp_mint_supply: 1_000_000,
+            token_a_reserve: 1_000_000_000,
+            token_a_decimals: 6,
+            token_b_reserve: 1_000_000_000_000,
+            token_b_decimals: 9,
+        };
+        // 1,000 of each token
+        assert_eq!(lp.calculate_pool_value(Rounding::Down), Some(2_000_000_000));
+        assert_eq!(
+            lp.cash_for_collateral(1_000, Rounding::Down),
+            Some(2_000_000)
+        );
+        assert_eq!(
+            lp.collateral_for_cash(2_000_000, Rounding::Down),
+            Some(1_000)
+        );
+
+        // skewing the reserves cannot increase the value of the pool
+        let skewed = ConstantProductLp {
+            token_a_reserve: 4_000_000_000,
+            token_b_reserve: 250_000_000_000,
+            ..lp
+        };
+        assert_eq!(
+            skewed.calculate_pool_value(Rounding::Down),
+            lp.calculate_pool_value(Rounding::Down)
+        );
+    }
+
+    #[test]
+    fn test_yield_bearing_exchange_rate() {
+        // 1 cToken = 1.02 USDC
+        let ctoken = YieldBearingToken {
+            total_liquidity_wads: 1_020_000_000u128 * u128::from(WAD),
+            collateral_supply: 1_000_000_000,
+            liquidity_decimals: 6,
+        };
+        assert_eq!(
+            ctoken.cash_for_collateral(1_000_000, Rounding::Down),
+            Some(1_020_000)
+        );
+        assert_eq!(
+            ctoken.collateral_for_cash(1_020_000, Rounding::Down),
+            Some(1_000_000)
+        );
+        assert_eq!(ctoken.collateral_for_cash(1, Rounding::Down), Some(0));
+        assert_eq!(ctoken.collateral_for_cash(1, Rounding::Up), Some(1));
+    }
+
+    #[test]
+    fn test_stable_swap_pools() {
+        let amp_factor = AmpFactor {
+            initial_amp_factor: 100,
+            target_amp_factor: 100,
+            ..Default::default()
+        };
+        let token = |amount| StableSwapReserve::Token { amount };
+
+        // two tokens match Saber
+        let saber = SaberSwap {
+            initial_amp_factor: 100,
+            target_amp_factor: 100,
+            lp_mint_supply: 2_000_000,
+            token_a_reserve: 1_500_000,
+            token_b_reserve: 700_000,
+            ..Default::default()
+        };
+        let pool = StableSwapPool {
+            amp_factor,
+            lp_mint_supply: 2_000_000,
+            reserves: vec![token(1_500_000), token(700_000)],
+        };
+        assert_eq!(pool.compute_d(Rounding::Down), saber.compute_d());
+
+        // a balanced pool is worth the sum of its reserves
+        let base = StableSwapPool {
+            amp_factor,
+            lp_mint_supply: 3_000_000,
+            reserves: vec![token(1_000_000), token(1_000_000), token(1_000_000)],
+        };
+        assert_eq!(base.compute_d(Rounding::Down), Some(3_000_000.into()));
+
+        // base LP tokens are valued recursively
+        let base = StableSwapPool {
+            lp_mint_supply: 1_500_000,
+            ..base
+        };
+        let metapool = CashStableSwap {
+            lp_mint_decimals: 6,
+            pool: StableSwapPool {
+                amp_factor,
+                lp_mint_supply: 2_000_000,
+                reserves: vec![
+                    token(1_000_000),
+                    StableSwapReserve::Lp {
+                        amount: 500_000,
+                        pool: Box::new(base),
+                    },
+                ],
+            },
+        };
+        assert_eq!(
+            metapool.pool.compute_d(Rounding::Down),
+            Some(2_000_000.into())
+        );
+        assert_eq!(
+            metapool.cash_for_collateral(1_000_000, Rounding::Down),
+            Some(1_000_000)
+        );
+    }
+
+    #[test]
+    fn test_cash_swap_scales_lp_decimals_down() {
+        // a balanced pool of two 9-decimal tokens, with 2 LP tokens of 9 decimals
+        let swap = CashSwap {
+            lp_mint_decimals: 9,
+            saber: SaberSwap {
+                initial_amp_factor: 100,
+                target_amp_factor: 100,
+                lp_mint_supply: 2_000_000_000,
+                token_a_reserve: 1_000_000_000,
+                token_b_reserve: 1_000_000_000,
+                ..Default::default()
+            },
+        };
+        // 1 LP token is worth 1 $CASH, not 10^6 $CASH
+        assert_eq!(
+            swap.cash_for_collateral(1_000_000_000, Rounding::Down),
+            Some(1_000_000)
+        );
+        assert_eq!(
+            swap.collateral_for_cash(1_000_000, Rounding::Down),
+            Some(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_rounding_is_conservative() {
+        let lp = ConstantProductLp {
+            lp_mint_supply: 3,
+            token_a_reserve: 7,
+            token_a_decimals: 6,
+            token_b_reserve: 11,
+            token_b_decimals: 6,
+        };
+        let down = lp.cash_for_collateral(1, Rounding::Down).unwrap();
+        let up = lp.cash_for_collateral(1, Rounding::Up).unwrap();
+        assert!(down < up);
+        assert!(lp.collateral_for_cash(down, Rounding::Down).unwrap() <= 1);
+    }
+}
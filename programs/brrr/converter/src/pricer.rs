@@ -0,0 +1,65 @@
+//! Collateral pricing interface
+
+/// Direction to round a conversion in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round towards zero.
+    Down,
+    /// Round away from zero.
+    Up,
+}
+
+/// Values a collateral token in $CASH.
+///
+/// Implementors must be consistent: converting an amount in one direction
+/// and back with [Rounding::Down] must never produce more than the original amount.
+pub trait CollateralPricer {
+    /// Calculates the value, in $CASH, of `collateral_amount` collateral tokens.
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64>;
+
+    /// Calculates the amount of collateral tokens worth `cash_amount` $CASH.
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64>;
+}
+
+/// Computes `a * b / c` with the given [Rounding].
+pub(crate) fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Option<u128> {
+    let product = a.checked_mul(b)?;
+    let quotient = product.checked_div(c)?;
+    match rounding {
+        Rounding::Down => Some(quotient),
+        Rounding::Up => {
+            if product.checked_rem(c)? == 0 {
+                Some(quotient)
+            } else {
+                quotient.checked_add(1)
+            }
+        }
+    }
+}
+
+/// Computes the square root of `n` with the given [Rounding].
+pub(crate) fn sqrt(n: u128, rounding: Rounding) -> Option<u128> {
+    if n < 2 {
+        return Some(n);
+    }
+    // Newton's method, starting from a power of two above the root.
+    let bits = 128u32.checked_sub(n.leading_zeros())?;
+    let mut x = 1u128.checked_shl(bits.checked_add(1)?.checked_div(2)?)?;
+    loop {
+        let y = x.checked_add(n.checked_div(x)?)?.checked_div(2)?;
+        if y >= x {
+            break;
+        }
+        x = y;
+    }
+    match rounding {
+        Rounding::Down => Some(x),
+        Rounding::Up => {
+            if x.checked_mul(x)? == n {
+                Some(x)
+            } else {
+                x.checked_add(1)
+            }
+        }
+    }
+}
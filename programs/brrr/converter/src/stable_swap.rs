@@ -0,0 +1,176 @@
+//! N-token stable swap pools and metapools
+
+use stable_swap_math::{bn::U192, curve::StableSwap};
+
+use crate::{mul_div_u192, scale_from_cash_decimals, scale_to_cash_decimals};
+use crate::{CollateralPricer, Rounding};
+
+/// Maximum number of Newton iterations used to compute the invariant.
+const MAX_ITERATIONS: usize = 256;
+
+/// Amplification coefficient (A) of a stable swap pool, which may be ramping.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct AmpFactor {
+    /// Initial amp factor.
+    pub initial_amp_factor: u64,
+    /// Target amp factor.
+    pub target_amp_factor: u64,
+    /// Current timestamp.
+    pub current_ts: i64,
+    /// Start ramp timestamp.
+    pub start_ramp_ts: i64,
+    /// Stop ramp timestamp.
+    pub stop_ramp_ts: i64,
+}
+
+impl AmpFactor {
+    /// Computes the current amp factor.
+    pub fn compute(&self) -> Option<u64> {
+        StableSwap::new(
+            self.initial_amp_factor,
+            self.target_amp_factor,
+            self.current_ts,
+            self.start_ramp_ts,
+            self.stop_ramp_ts,
+        )
+        .compute_amp_factor()
+    }
+}
+
+/// A reserve of a [StableSwapPool].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum StableSwapReserve {
+    /// A plain token, valued 1:1.
+    Token {
+        /// Amount of tokens in the reserve.
+        amount: u64,
+    },
+    /// LP tokens of a base pool, valued at the base pool's virtual price.
+    /// A pool holding such a reserve is a metapool.
+    Lp {
+        /// Amount of base LP tokens in the reserve.
+        amount: u64,
+        /// The base pool.
+        pool: Box<StableSwapPool>,
+    },
+}
+
+impl StableSwapReserve {
+    /// Value of the reserve, in virtual tokens of the pool.
+    pub fn value(&self, rounding: Rounding) -> Option<U192> {
+        match self {
+            StableSwapReserve::Token { amount } => Some((*amount).into()),
+            StableSwapReserve::Lp { amount, pool } => pool.virtual_amount(*amount, rounding),
+        }
+    }
+}
+
+/// A stable swap pool with any number of reserves, all denominated in the decimals of the LP mint.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct StableSwapPool {
+    /// Amplification coefficient.
+    pub amp_factor: AmpFactor,
+    /// Total supply of LP tokens.
+    pub lp_mint_supply: u64,
+    /// Reserves of the pool.
+    pub reserves: Vec<StableSwapReserve>,
+}
+
+impl StableSwapPool {
+    /// Computes the stable swap invariant (D), valuing metapool reserves at the
+    /// virtual price of their base pools.
+    ///
+    /// With two plain reserves, this is equal to [crate::SaberSwap::compute_d].
+    pub fn compute_d(&self, rounding: Rounding) -> Option<U192> {
+        let values = self
+            .reserves
+            .iter()
+            .map(|reserve| reserve.value(rounding))
+            .collect::<Option<Vec<_>>>()?;
+        compute_d(self.amp_factor.compute()?, &values)
+    }
+
+    /// Calculates the value of `lp_amount` LP tokens, in virtual tokens.
+    pub fn virtual_amount(&self, lp_amount: u64, rounding: Rounding) -> Option<U192> {
+        mul_div_u192(
+            self.compute_d(rounding)?,
+            lp_amount.into(),
+            self.lp_mint_supply.into(),
+            rounding,
+        )
+    }
+}
+
+/// Computes the stable swap invariant of the given reserve values.
+///
+/// ```text
+/// A * sum(x_i) * n**n + D = A * D * n**n + D**(n+1) / (n**n * prod(x_i))
+/// ```
+fn compute_d(amp_factor: u64, values: &[U192]) -> Option<U192> {
+    let n_coins = U192::from(values.len());
+    let sum_x = values
+        .iter()
+        .try_fold(U192::zero(), |sum, value| sum.checked_add(*value))?;
+    if sum_x.is_zero() {
+        return Some(sum_x);
+    }
+    let ann = U192::from(amp_factor).checked_mul(n_coins)?;
+    let leverage = sum_x.checked_mul(ann)?;
+
+    // Newton's method to approximate D
+    let mut d = sum_x;
+    for _ in 0..MAX_ITERATIONS {
+        let d_prod = values.iter().try_fold(d, |d_prod, value| {
+            d_prod
+                .checked_mul(d)?
+                .checked_div(value.checked_mul(n_coins)?)
+        })?;
+        let d_prev = d;
+        // d = (ann * sum_x + d_prod * n_coins) * d / ((ann - 1) * d + (n_coins + 1) * d_prod)
+        let numerator = d.checked_mul(d_prod.checked_mul(n_coins)?.checked_add(leverage)?)?;
+        let denominator = d
+            .checked_mul(ann.checked_sub(1.into())?)?
+            .checked_add(d_prod.checked_mul(n_coins.checked_add(1.into())?)?)?;
+        d = numerator.checked_div(denominator)?;
+        // Equality with the precision of 1
+        let delta = if d > d_prev {
+            d.checked_sub(d_prev)?
+        } else {
+            d_prev.checked_sub(d)?
+        };
+        if delta <= 1.into() {
+            break;
+        }
+    }
+    Some(d)
+}
+
+/// A [StableSwapPool] LP and number of decimals.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct CashStableSwap {
+    /// Decimals of the LP mint.
+    pub lp_mint_decimals: u8,
+    /// The pool.
+    pub pool: StableSwapPool,
+}
+
+impl CollateralPricer for CashStableSwap {
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64> {
+        let virtual_amount = self.pool.virtual_amount(collateral_amount, rounding)?;
+        scale_to_cash_decimals(virtual_amount.to_u128()?, self.lp_mint_decimals, rounding)?
+            .try_into()
+            .ok()
+    }
+
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64> {
+        let virtual_amount =
+            scale_from_cash_decimals(cash_amount.into(), self.lp_mint_decimals, rounding)?;
+        mul_div_u192(
+            virtual_amount.into(),
+            self.pool.lp_mint_supply.into(),
+            self.pool.compute_d(rounding)?,
+            rounding,
+        )?
+        .to_u64()
+    }
+}
@@ -0,0 +1,60 @@
+//! Yield-bearing token pricing
+
+use stable_swap_math::bn::U192;
+
+use crate::{
+    mul_div_u192, scale_from_cash_decimals, scale_to_cash_decimals, CollateralPricer, Rounding,
+};
+
+/// Scale of a wad, a fixed-point number with 18 decimals.
+pub const WAD: u64 = 1_000_000_000_000_000_000;
+
+/// A yield-bearing token, such as a lending protocol's cToken, which is redeemable
+/// for a growing amount of an underlying stablecoin.
+///
+/// The underlying stablecoin is valued 1:1 with $CASH after decimal scaling.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct YieldBearingToken {
+    /// Total amount of underlying tokens owed to holders of the yield-bearing token, as a wad.
+    pub total_liquidity_wads: u128,
+    /// Total supply of the yield-bearing token.
+    pub collateral_supply: u64,
+    /// Decimals of the underlying stablecoin.
+    pub liquidity_decimals: u8,
+}
+
+impl YieldBearingToken {
+    fn collateral_supply_wads(&self) -> Option<U192> {
+        U192::from(self.collateral_supply).checked_mul(WAD.into())
+    }
+}
+
+impl CollateralPricer for YieldBearingToken {
+    fn cash_for_collateral(&self, collateral_amount: u64, rounding: Rounding) -> Option<u64> {
+        let liquidity_amount = mul_div_u192(
+            collateral_amount.into(),
+            self.total_liquidity_wads.into(),
+            self.collateral_supply_wads()?,
+            rounding,
+        )?;
+        scale_to_cash_decimals(
+            liquidity_amount.to_u128()?,
+            self.liquidity_decimals,
+            rounding,
+        )?
+        .try_into()
+        .ok()
+    }
+
+    fn collateral_for_cash(&self, cash_amount: u64, rounding: Rounding) -> Option<u64> {
+        let liquidity_amount =
+            scale_from_cash_decimals(cash_amount.into(), self.liquidity_decimals, rounding)?;
+        mul_div_u192(
+            liquidity_amount.into(),
+            self.collateral_supply_wads()?,
+            self.total_liquidity_wads.into(),
+            rounding,
+        )?
+        .to_u64()
+    }
+}
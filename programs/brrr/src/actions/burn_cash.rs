@@ -1,10 +1,10 @@
-use std::convert::TryInto;
-
 use crate::*;
 use anchor_lang::prelude::*;
-use converter::CashSwap;
+use converter::{CollateralPricer, Rounding};
 use vipers::{assert_keys_eq, unwrap_int, validate::Validate};
 
+use super::{assert_source_allowance, validate_source_authority, CashAccounts};
+
 /// Prints $CASH.
 pub fn burn_cash(ctx: Context<BurnCash>, burn_amount: u64) -> Result<()> {
     ctx.accounts.burn_cash(burn_amount)
@@ -12,65 +12,150 @@ pub fn burn_cash(ctx: Context<BurnCash>, burn_amount: u64) -> Result<()> {
 
 impl<'info> BurnCash<'info> {
     /// We like the $CASH.
-    fn burn_cash(&self, burn_amount: u64) -> Result<()> {
-        let swap: CashSwap = (&self.common.saber_swap).try_into()?;
-        let withdraw_pool_token_amount =
-            unwrap_int!(swap.calculate_pool_tokens_for_cash(burn_amount));
-        if withdraw_pool_token_amount == 0 {
+    pub(crate) fn burn_cash(&self, burn_amount: u64) -> Result<()> {
+        self.common.cash_accounts().burn_cash(
+            &self.burner,
+            &self.burned_cash_source,
+            &self.withdraw_destination,
+            &self.author_fee_destination,
+            &self.protocol_fee_destination,
+            &self.withdraw_authority,
+            burn_amount,
+        )
+    }
+}
+
+impl<'a, 'info, P: PricingAccounts<'info>> CashAccounts<'a, 'info, P> {
+    /// Burns `burn_amount` $CASH and withdraws collateral tokens of equal value.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn burn_cash(
+        &self,
+        burner: &Signer<'info>,
+        burned_cash_source: &Account<'info, TokenAccount>,
+        withdraw_destination: &Account<'info, TokenAccount>,
+        author_fee_destination: &Account<'info, TokenAccount>,
+        protocol_fee_destination: &Account<'info, TokenAccount>,
+        withdraw_authority: &UncheckedAccount<'info>,
+        burn_amount: u64,
+    ) -> Result<()> {
+        assert_source_allowance(burner.key(), burned_cash_source, burn_amount)?;
+        let pricer = self.pricing.pricer()?;
+        let withdraw_amount = unwrap_int!(pricer.collateral_for_cash(burn_amount, Rounding::Down));
+        if withdraw_amount == 0 {
             return Ok(());
         }
 
-        let current_balance = self.common.crate_collateral_tokens.amount;
-        require!(
-            current_balance >= withdraw_pool_token_amount,
-            InsufficientFunds
-        );
+        let current_balance = self.crate_collateral_tokens.amount;
+        require!(current_balance >= withdraw_amount, InsufficientFunds);
+
+        self.redeem(
+            burned_cash_source.to_account_info(),
+            burner.to_account_info(),
+            &[],
+            withdraw_destination.to_account_info(),
+            author_fee_destination.to_account_info(),
+            protocol_fee_destination.to_account_info(),
+            withdraw_authority.to_account_info(),
+            burn_amount,
+            withdraw_amount,
+        )?;
+
+        emit!(BurnCashEvent {
+            burner: burner.key(),
+            collateral_mint: self.crate_collateral_tokens.mint,
+            burn_amount,
+            withdraw_amount,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+
+        Ok(())
+    }
 
+    /// Burns `burn_amount` $CASH from the `cash_source` and withdraws
+    /// `withdraw_amount` collateral tokens to the `withdraw_destination`.
+    ///
+    /// The `authority` of the `cash_source` signs with the `signer_seeds`, if any.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn redeem(
+        &self,
+        cash_source: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        signer_seeds: &[&[&[u8]]],
+        withdraw_destination: AccountInfo<'info>,
+        author_fee_destination: AccountInfo<'info>,
+        protocol_fee_destination: AccountInfo<'info>,
+        withdraw_authority: AccountInfo<'info>,
+        burn_amount: u64,
+        withdraw_amount: u64,
+    ) -> Result<()> {
         // Burn the $CASH.
         anchor_spl::token::burn(
-            CpiContext::new(
-                self.common.token_program.to_account_info(),
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
                 anchor_spl::token::Burn {
-                    mint: self.common.crate_mint.to_account_info(),
-                    from: self.burned_cash_source.to_account_info(),
-                    authority: self.burner.to_account_info(),
+                    mint: self.crate_mint.to_account_info(),
+                    from: cash_source,
+                    authority,
                 },
+                signer_seeds,
             ),
             burn_amount,
         )?;
 
-        // Withdraw the LP tokens from the pool.
-        crate_token::cpi::withdraw(
-            CpiContext::new_with_signer(
-                self.common.crate_token_program.to_account_info(),
-                crate_token::cpi::accounts::Withdraw {
-                    crate_token: self.common.crate_token.to_account_info(),
-                    crate_underlying: self.common.crate_collateral_tokens.to_account_info(),
-                    withdraw_authority: self.withdraw_authority.to_account_info(),
-                    withdraw_destination: self.withdraw_destination.to_account_info(),
-                    author_fee_destination: self.author_fee_destination.to_account_info(),
-                    protocol_fee_destination: self.protocol_fee_destination.to_account_info(),
-                    token_program: self.common.token_program.to_account_info(),
-                },
-                WITHDRAW_AUTHORITY_SIGNER_SEEDS,
-            ),
-            withdraw_pool_token_amount,
-        )?;
+        // Withdraw the collateral tokens from the pool.
+        let authority = BankAuthority::withdraw(self.bank.key(), withdraw_authority.key())?;
+        authority.with_signer_seeds(|signer_seeds| {
+            crate_token::cpi::withdraw(
+                CpiContext::new_with_signer(
+                    self.crate_token_program.to_account_info(),
+                    crate_token::cpi::accounts::Withdraw {
+                        crate_token: self.crate_token.to_account_info(),
+                        crate_underlying: self.crate_collateral_tokens.to_account_info(),
+                        withdraw_authority,
+                        withdraw_destination,
+                        author_fee_destination,
+                        protocol_fee_destination,
+                        token_program: self.token_program.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                withdraw_amount,
+            )
+        })?;
 
         Ok(())
     }
-}
 
-impl<'info> Validate<'info> for BurnCash<'info> {
-    fn validate(&self) -> Result<()> {
-        self.common.validate()?;
-        assert_keys_eq!(self.burner, self.burned_cash_source.owner);
-        assert_keys_eq!(self.burned_cash_source.mint, self.common.crate_mint);
+    /// Validates the accounts of a burn instruction.
+    pub(crate) fn validate_burn(
+        &self,
+        burner: &Signer<'info>,
+        burned_cash_source: &Account<'info, TokenAccount>,
+        withdraw_destination: &Account<'info, TokenAccount>,
+        withdraw_authority: &UncheckedAccount<'info>,
+    ) -> Result<()> {
+        self.validate()?;
+        validate_source_authority(burner.key(), burned_cash_source)?;
+        assert_keys_eq!(burned_cash_source.mint, self.crate_mint.key());
 
-        assert_keys_eq!(self.withdraw_destination.mint, self.common.collateral.mint);
+        assert_keys_eq!(withdraw_destination.mint, self.collateral.mint);
         // author_fee_destination is validated by Crate
         // protocol_fee_destination is validated by Crate
-        assert_keys_eq!(self.withdraw_authority, WITHDRAW_AUTHORITY_ADDRESS);
+        assert_keys_eq!(
+            withdraw_authority.key(),
+            self.crate_token.withdraw_authority
+        );
         Ok(())
     }
 }
+
+impl<'info> Validate<'info> for BurnCash<'info> {
+    fn validate(&self) -> Result<()> {
+        self.common.cash_accounts().validate_burn(
+            &self.burner,
+            &self.burned_cash_source,
+            &self.withdraw_destination,
+            &self.withdraw_authority,
+        )
+    }
+}
@@ -0,0 +1,29 @@
+use crate::metapool::SaberMetapoolAccounts;
+use crate::*;
+use anchor_lang::prelude::*;
+
+/// Burns $CASH for the Arrow LP of a Saber metapool.
+pub fn burn_cash_metapool<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnCash<'info>>,
+    burn_amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let metapool =
+        SaberMetapoolAccounts::load(&accounts.common.saber_swap, ctx.remaining_accounts)?;
+    let cash_accounts = accounts.common.cash_accounts_with(&metapool);
+    cash_accounts.validate_burn(
+        &accounts.burner,
+        &accounts.burned_cash_source,
+        &accounts.withdraw_destination,
+        &accounts.withdraw_authority,
+    )?;
+    cash_accounts.burn_cash(
+        &accounts.burner,
+        &accounts.burned_cash_source,
+        &accounts.withdraw_destination,
+        &accounts.author_fee_destination,
+        &accounts.protocol_fee_destination,
+        &accounts.withdraw_authority,
+        burn_amount,
+    )
+}
@@ -0,0 +1,90 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use converter::{CollateralPricer, Rounding};
+use vipers::{unwrap_int, validate::Validate};
+
+use super::multi::{CollateralGroup, CollateralGroups, GroupPricing};
+
+/// Number of user token accounts in each group of [brrr::burn_cash_multi]:
+/// `[withdraw_destination, author_fee_destination, protocol_fee_destination]`.
+pub const BURN_CASH_MULTI_TOKEN_ACCOUNTS: usize = 3;
+
+/// Burns $CASH for several collaterals.
+pub fn burn_cash_multi<'info>(
+    ctx: Context<'_, '_, '_, 'info, BurnCashMulti<'info>>,
+    burn_amounts: Vec<u64>,
+    min_withdraw_value: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let mut withdraw_value: u64 = 0;
+
+    let mut groups = CollateralGroups::new(ctx.remaining_accounts, BURN_CASH_MULTI_TOKEN_ACCOUNTS);
+    for burn_amount in burn_amounts {
+        let group = groups.next_group()?;
+        let value = match &group.pricing {
+            GroupPricing::Saber(pricing) => accounts.burn_group(&group, pricing, burn_amount)?,
+            GroupPricing::Stablecoin(pricing) => {
+                accounts.burn_group(&group, pricing, burn_amount)?
+            }
+            GroupPricing::YieldBearing(pricing) => {
+                accounts.burn_group(&group, pricing, burn_amount)?
+            }
+        };
+        withdraw_value = unwrap_int!(withdraw_value.checked_add(value));
+    }
+    groups.finish()?;
+
+    require!(
+        withdraw_value >= min_withdraw_value,
+        WithdrawSlippageExceeded
+    );
+    Ok(())
+}
+
+impl<'info> BurnCashMulti<'info> {
+    /// Burns $CASH for a single collateral, returning the value, in $CASH,
+    /// of the collateral received after fees.
+    fn burn_group<P: PricingAccounts<'info>>(
+        &self,
+        group: &CollateralGroup<'info>,
+        pricing: &P,
+        burn_amount: u64,
+    ) -> Result<u64> {
+        let withdraw_destination = &group.token_accounts[0];
+        let balance_before = withdraw_destination.amount;
+        {
+            let cash_accounts = self.common.cash_accounts(group, pricing);
+            let author_fee_destination = &group.token_accounts[1];
+            let protocol_fee_destination = &group.token_accounts[2];
+            cash_accounts.validate_burn(
+                &self.burner,
+                &self.burned_cash_source,
+                withdraw_destination,
+                &self.withdraw_authority,
+            )?;
+            cash_accounts.burn_cash(
+                &self.burner,
+                &self.burned_cash_source,
+                withdraw_destination,
+                author_fee_destination,
+                protocol_fee_destination,
+                &self.withdraw_authority,
+                burn_amount,
+            )?;
+        }
+
+        let balance_after =
+            Account::<TokenAccount>::try_from(&withdraw_destination.to_account_info())?.amount;
+        let received = unwrap_int!(balance_after.checked_sub(balance_before));
+        Ok(unwrap_int!(pricing
+            .pricer()?
+            .cash_for_collateral(received, Rounding::Down)))
+    }
+}
+
+impl<'info> Validate<'info> for BurnCashMulti<'info> {
+    fn validate(&self) -> Result<()> {
+        // each group is validated as it is burned
+        self.common.validate()
+    }
+}
@@ -0,0 +1,33 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::validate::Validate;
+
+/// Burns $CASH for a stablecoin.
+pub fn burn_cash_stablecoin(ctx: Context<BurnCashStablecoin>, burn_amount: u64) -> Result<()> {
+    ctx.accounts.burn_cash(burn_amount)
+}
+
+impl<'info> BurnCashStablecoin<'info> {
+    fn burn_cash(&self, burn_amount: u64) -> Result<()> {
+        self.common.cash_accounts().burn_cash(
+            &self.burner,
+            &self.burned_cash_source,
+            &self.withdraw_destination,
+            &self.author_fee_destination,
+            &self.protocol_fee_destination,
+            &self.withdraw_authority,
+            burn_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for BurnCashStablecoin<'info> {
+    fn validate(&self) -> Result<()> {
+        self.common.cash_accounts().validate_burn(
+            &self.burner,
+            &self.burned_cash_source,
+            &self.withdraw_destination,
+            &self.withdraw_authority,
+        )
+    }
+}
@@ -0,0 +1,50 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, unwrap_int, validate::Validate};
+
+/// Burns $CASH for raw Saber LP tokens.
+pub fn burn_cash_to_lp(ctx: Context<BurnCashToLp>, burn_amount: u64) -> Result<()> {
+    ctx.accounts.burn_cash_to_lp(burn_amount)
+}
+
+impl<'info> BurnCashToLp<'info> {
+    pub(crate) fn burn_cash_to_lp(&mut self, burn_amount: u64) -> Result<()> {
+        // only the Arrow tokens received net of fees are unstaked
+        let arrow_balance_before = self.burn_cash.withdraw_destination.amount;
+        self.burn_cash.burn_cash(burn_amount)?;
+        self.burn_cash.withdraw_destination.reload()?;
+        let unstake_amount = unwrap_int!(self
+            .burn_cash
+            .withdraw_destination
+            .amount
+            .checked_sub(arrow_balance_before));
+        if unstake_amount == 0 {
+            return Ok(());
+        }
+
+        let burn_cash = &self.burn_cash;
+        self.arrow_stake.unstake(
+            &burn_cash.common.saber_swap.arrow,
+            &burn_cash.burner,
+            &burn_cash.withdraw_destination,
+            &self.lp_destination,
+            &self.sunny_pool_fee_destination,
+            &burn_cash.common.token_program,
+            unstake_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for BurnCashToLp<'info> {
+    fn validate(&self) -> Result<()> {
+        self.burn_cash.validate()?;
+        let arrow = &self.burn_cash.common.saber_swap.arrow;
+        self.arrow_stake.validate_arrow(arrow)?;
+        assert_keys_eq!(
+            self.burn_cash.withdraw_destination.owner,
+            self.burn_cash.burner
+        );
+        assert_keys_eq!(self.lp_destination.mint, arrow.vendor_miner.mint);
+        Ok(())
+    }
+}
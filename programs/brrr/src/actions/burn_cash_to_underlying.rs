@@ -0,0 +1,60 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, unwrap_int, unwrap_opt, validate::Validate};
+
+/// Burns $CASH for an underlying token of a Saber pool.
+pub fn burn_cash_to_underlying(
+    ctx: Context<BurnCashToUnderlying>,
+    burn_amount: u64,
+    min_withdraw_amount: u64,
+) -> Result<()> {
+    ctx.accounts
+        .burn_cash_to_underlying(burn_amount, min_withdraw_amount)
+}
+
+impl<'info> BurnCashToUnderlying<'info> {
+    fn burn_cash_to_underlying(
+        &mut self,
+        burn_amount: u64,
+        min_withdraw_amount: u64,
+    ) -> Result<()> {
+        let lp_balance_before = self.burn_cash_to_lp.lp_destination.amount;
+        self.burn_cash_to_lp.burn_cash_to_lp(burn_amount)?;
+        self.burn_cash_to_lp.lp_destination.reload()?;
+        let lp_amount = unwrap_int!(self
+            .burn_cash_to_lp
+            .lp_destination
+            .amount
+            .checked_sub(lp_balance_before));
+
+        let to_lp = &self.burn_cash_to_lp;
+        // the minimum is enforced by Saber
+        self.saber.withdraw_one(
+            &to_lp.burn_cash.common.saber_swap,
+            &to_lp.burn_cash.burner,
+            &to_lp.lp_destination,
+            &self.underlying_destination,
+            &self.admin_fees,
+            &to_lp.burn_cash.common.token_program,
+            lp_amount,
+            min_withdraw_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for BurnCashToUnderlying<'info> {
+    fn validate(&self) -> Result<()> {
+        self.burn_cash_to_lp.validate()?;
+        let burn_cash = &self.burn_cash_to_lp.burn_cash;
+        let swap = &burn_cash.common.saber_swap;
+        self.saber.validate_swap(swap)?;
+        assert_keys_eq!(self.burn_cash_to_lp.lp_destination.owner, burn_cash.burner);
+
+        let admin_fees = unwrap_opt!(
+            SaberLiquidityAccounts::admin_fees_of(swap, self.underlying_destination.mint),
+            TokenNotInPool
+        );
+        assert_keys_eq!(self.admin_fees, admin_fees);
+        Ok(())
+    }
+}
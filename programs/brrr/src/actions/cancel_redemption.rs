@@ -0,0 +1,54 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use vipers::{assert_keys_eq, validate::Validate};
+
+/// Cancels a [RedemptionTicket].
+pub fn cancel_redemption(ctx: Context<CancelRedemption>) -> Result<()> {
+    let amount = ctx.accounts.ticket.cash_amount;
+    require!(amount > 0, RedemptionTicketEmpty);
+
+    let queue = &ctx.accounts.queue;
+    let signer_seeds: &[&[&[u8]]] = &[gen_redemption_queue_signer_seeds!(queue)];
+    anchor_spl::token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.escrow.to_account_info(),
+                to: ctx.accounts.cash_destination.to_account_info(),
+                authority: queue.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.cash_amount = 0;
+
+    emit!(CancelRedemptionEvent {
+        queue: queue.key(),
+        ticket: ticket.key(),
+        owner: ticket.owner,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    // a skipped ticket is behind the head and is closed now; any other ticket
+    // is closed once the queue is filled past it
+    if ticket.index < queue.head {
+        ticket.close(ctx.accounts.owner.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for CancelRedemption<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.ticket.queue, self.queue);
+        assert_keys_eq!(self.ticket.owner, self.owner);
+        assert_keys_eq!(self.escrow, self.queue.escrow);
+        assert_keys_eq!(self.cash_destination.mint, self.escrow.mint);
+        Ok(())
+    }
+}
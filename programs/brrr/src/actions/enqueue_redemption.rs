@@ -0,0 +1,51 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, unwrap_bump, unwrap_int, validate::Validate};
+
+/// Escrows $CASH in a [RedemptionQueue].
+pub fn enqueue_redemption(ctx: Context<EnqueueRedemption>, amount: u64) -> Result<()> {
+    require!(amount > 0, RedemptionAmountZero);
+
+    anchor_spl::token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            anchor_spl::token::Transfer {
+                from: ctx.accounts.cash_source.to_account_info(),
+                to: ctx.accounts.escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let queue = &mut ctx.accounts.queue;
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.queue = queue.key();
+    ticket.index = queue.tail;
+    ticket.bump = unwrap_bump!(ctx, "ticket");
+    ticket.owner = ctx.accounts.owner.key();
+    ticket.withdraw_destination = ctx.accounts.withdraw_destination.key();
+    ticket.cash_amount = amount;
+    queue.tail = unwrap_int!(queue.tail.checked_add(1));
+
+    emit!(EnqueueRedemptionEvent {
+        queue: queue.key(),
+        ticket: ticket.key(),
+        owner: ticket.owner,
+        index: ticket.index,
+        amount,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for EnqueueRedemption<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.escrow, self.queue.escrow);
+        assert_keys_eq!(self.collateral, self.queue.collateral);
+        assert_keys_eq!(self.cash_source.owner, self.owner);
+        assert_keys_eq!(self.withdraw_destination.mint, self.collateral.mint);
+        Ok(())
+    }
+}
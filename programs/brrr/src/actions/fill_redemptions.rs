@@ -0,0 +1,176 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountsClose, AccountsExit};
+use converter::{CollateralPricer, Rounding};
+use vipers::{assert_keys_eq, invariant, unwrap_int, validate::Validate};
+
+use super::CashAccounts;
+
+/// Number of remaining accounts per [RedemptionTicket] filled.
+pub const FILL_REDEMPTIONS_ACCOUNTS_PER_TICKET: usize = 3;
+
+/// Fills a [RedemptionQueue] of a Saber LP.
+pub fn fill_redemptions_saber<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRedemptionsSaber<'info>>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    accounts
+        .redemptions
+        .fill(&accounts.common.cash_accounts(), ctx.remaining_accounts)
+}
+
+/// Fills a [RedemptionQueue] of a stablecoin.
+pub fn fill_redemptions_stablecoin<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRedemptionsStablecoin<'info>>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    accounts
+        .redemptions
+        .fill(&accounts.common.cash_accounts(), ctx.remaining_accounts)
+}
+
+/// Fills a [RedemptionQueue] of a yield-bearing token.
+pub fn fill_redemptions_yield_bearing<'info>(
+    ctx: Context<'_, '_, '_, 'info, FillRedemptionsYieldBearing<'info>>,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    accounts
+        .redemptions
+        .fill(&accounts.common.cash_accounts(), ctx.remaining_accounts)
+}
+
+impl<'info> RedemptionQueueAccounts<'info> {
+    /// Fills tickets from the head of the queue until the queue is empty,
+    /// the crate runs out of collateral, or the passed tickets run out.
+    ///
+    /// Tickets whose withdraw destination can no longer receive the collateral
+    /// are skipped, so that they cannot stall the queue. Their owners may still
+    /// cancel them.
+    fn fill<P: PricingAccounts<'info>>(
+        &mut self,
+        cash_accounts: &CashAccounts<'_, 'info, P>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<()> {
+        invariant!(
+            remaining_accounts.len() % FILL_REDEMPTIONS_ACCOUNTS_PER_TICKET == 0,
+            InvalidRedemptionTickets
+        );
+        let pricer = cash_accounts.pricing.pricer()?;
+        let queue: RedemptionQueue = **self.queue;
+        let signer_seeds: &[&[&[u8]]] = &[gen_redemption_queue_signer_seeds!(queue)];
+        let mut available = cash_accounts.crate_collateral_tokens.amount;
+
+        for group in remaining_accounts.chunks_exact(FILL_REDEMPTIONS_ACCOUNTS_PER_TICKET) {
+            let mut ticket: Account<RedemptionTicket> = Account::try_from(&group[0])?;
+            let withdraw_destination = &group[1];
+            let owner = &group[2];
+            invariant!(group[0].is_writable && group[1].is_writable && group[2].is_writable);
+            assert_keys_eq!(ticket.queue, self.queue);
+            invariant!(ticket.index == self.queue.head, InvalidRedemptionTickets);
+            assert_keys_eq!(ticket.withdraw_destination, withdraw_destination.key());
+            assert_keys_eq!(ticket.owner, owner.key());
+
+            if ticket.cash_amount > 0
+                && !can_receive(withdraw_destination, &cash_accounts.collateral.mint)
+            {
+                emit!(SkipRedemptionEvent {
+                    queue: self.queue.key(),
+                    ticket: ticket.key(),
+                    owner: ticket.owner,
+                    remaining_amount: ticket.cash_amount,
+                    timestamp: Clock::get()?.unix_timestamp
+                });
+                self.queue.head = unwrap_int!(self.queue.head.checked_add(1));
+                continue;
+            }
+
+            if ticket.cash_amount > 0 {
+                let fillable = unwrap_int!(pricer.cash_for_collateral(available, Rounding::Down));
+                let burn_amount = ticket.cash_amount.min(fillable);
+                let withdraw_amount =
+                    unwrap_int!(pricer.collateral_for_cash(burn_amount, Rounding::Down));
+                if withdraw_amount == 0 {
+                    break;
+                }
+
+                cash_accounts.redeem(
+                    self.escrow.to_account_info(),
+                    self.queue.to_account_info(),
+                    signer_seeds,
+                    withdraw_destination.clone(),
+                    self.author_fee_destination.to_account_info(),
+                    self.protocol_fee_destination.to_account_info(),
+                    self.withdraw_authority.to_account_info(),
+                    burn_amount,
+                    withdraw_amount,
+                )?;
+                available = unwrap_int!(available.checked_sub(withdraw_amount));
+                ticket.cash_amount = unwrap_int!(ticket.cash_amount.checked_sub(burn_amount));
+
+                emit!(FillRedemptionEvent {
+                    queue: self.queue.key(),
+                    ticket: ticket.key(),
+                    owner: ticket.owner,
+                    burn_amount,
+                    withdraw_amount,
+                    remaining_amount: ticket.cash_amount,
+                    timestamp: Clock::get()?.unix_timestamp
+                });
+
+                if ticket.cash_amount > 0 {
+                    // partially filled; the ticket stays at the head of the queue
+                    ticket.exit(&crate::ID)?;
+                    break;
+                }
+            }
+
+            ticket.close(owner.clone())?;
+            self.queue.head = unwrap_int!(self.queue.head.checked_add(1));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the queue against the [CashAccounts] of its collateral.
+    fn validate_for<P: PricingAccounts<'info>>(
+        &self,
+        cash_accounts: &CashAccounts<'_, 'info, P>,
+    ) -> Result<()> {
+        cash_accounts.validate()?;
+        assert_keys_eq!(self.queue.collateral, cash_accounts.collateral.key());
+        assert_keys_eq!(self.escrow, self.queue.escrow);
+        // author_fee_destination is validated by Crate
+        // protocol_fee_destination is validated by Crate
+        assert_keys_eq!(
+            self.withdraw_authority,
+            cash_accounts.crate_token.withdraw_authority
+        );
+        Ok(())
+    }
+}
+
+/// Returns true if the `destination` is an unfrozen token account of the `mint`.
+fn can_receive(destination: &AccountInfo, mint: &Pubkey) -> bool {
+    match Account::<TokenAccount>::try_from(destination) {
+        Ok(destination) => destination.mint == *mint && !destination.is_frozen(),
+        Err(_) => false,
+    }
+}
+
+impl<'info> Validate<'info> for FillRedemptionsSaber<'info> {
+    fn validate(&self) -> Result<()> {
+        self.redemptions.validate_for(&self.common.cash_accounts())
+    }
+}
+
+impl<'info> Validate<'info> for FillRedemptionsStablecoin<'info> {
+    fn validate(&self) -> Result<()> {
+        self.redemptions.validate_for(&self.common.cash_accounts())
+    }
+}
+
+impl<'info> Validate<'info> for FillRedemptionsYieldBearing<'info> {
+    fn validate(&self) -> Result<()> {
+        self.redemptions.validate_for(&self.common.cash_accounts())
+    }
+}
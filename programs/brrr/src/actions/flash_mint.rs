@@ -0,0 +1,106 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+};
+use vipers::{assert_keys_eq, assert_keys_neq, invariant, unwrap_int, validate::Validate};
+
+/// Flash mints $CASH.
+pub fn flash_mint<'info>(
+    ctx: Context<'_, '_, '_, 'info, FlashMint<'info>>,
+    amount: u64,
+    data: Vec<u8>,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let bank = &accounts.bank;
+    require!(
+        amount > 0 && amount <= bank.flash_mint_ceiling,
+        FlashMintCeilingExceeded
+    );
+    let fee = unwrap_int!(bankman::apply_bps(amount, bank.flash_mint_fee_bps));
+    let repay_amount = unwrap_int!(amount.checked_add(fee));
+
+    let issue_authority = BankAuthority::issue(bank.key(), accounts.issue_authority.key())?;
+    issue_authority.with_signer_seeds(|signer_seeds| {
+        crate_token::cpi::issue(
+            CpiContext::new_with_signer(
+                accounts.crate_token_program.to_account_info(),
+                crate_token::cpi::accounts::Issue {
+                    crate_token: accounts.crate_token.to_account_info(),
+                    crate_mint: accounts.crate_mint.to_account_info(),
+                    issue_authority: accounts.issue_authority.to_account_info(),
+                    mint_destination: accounts.mint_destination.to_account_info(),
+
+                    // there are no author/protocol fees, so we pass in garbage here
+                    author_fee_destination: accounts.mint_destination.to_account_info(),
+                    protocol_fee_destination: accounts.mint_destination.to_account_info(),
+
+                    token_program: accounts.token_program.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )
+    })?;
+
+    invoke(
+        &Instruction {
+            program_id: accounts.receiver_program.key(),
+            accounts: ctx
+                .remaining_accounts
+                .iter()
+                .map(|info| AccountMeta {
+                    pubkey: info.key(),
+                    is_signer: info.is_signer,
+                    is_writable: info.is_writable,
+                })
+                .collect(),
+            data,
+        },
+        ctx.remaining_accounts,
+    )?;
+
+    anchor_spl::token::burn(
+        CpiContext::new(
+            accounts.token_program.to_account_info(),
+            anchor_spl::token::Burn {
+                mint: accounts.crate_mint.to_account_info(),
+                from: accounts.repay_source.to_account_info(),
+                authority: accounts.borrower.to_account_info(),
+            },
+        ),
+        repay_amount,
+    )?;
+
+    emit!(FlashMintEvent {
+        borrower: accounts.borrower.key(),
+        receiver_program: accounts.receiver_program.key(),
+        amount,
+        fee,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for FlashMint<'info> {
+    fn validate(&self) -> Result<()> {
+        invariant!(!self.bank.paused, BankPaused);
+        assert_keys_eq!(self.bank.crate_token, self.crate_token);
+        assert_keys_eq!(self.bank.crate_mint, self.crate_mint);
+        assert_keys_eq!(self.crate_mint.key(), self.crate_token.mint);
+        assert_keys_eq!(self.mint_destination.mint, self.crate_mint);
+        assert_keys_eq!(self.repay_source.mint, self.crate_mint);
+        assert_keys_eq!(self.repay_source.owner, self.borrower);
+        assert_keys_eq!(self.issue_authority, self.crate_token.issue_authority);
+
+        invariant!(self.receiver_program.executable, FlashMintInvalidReceiver);
+        assert_keys_neq!(
+            self.receiver_program.key(),
+            crate::ID,
+            FlashMintInvalidReceiver
+        );
+        Ok(())
+    }
+}
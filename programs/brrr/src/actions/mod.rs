@@ -1,24 +1,161 @@
 //! Processes actions related to the money printer
 
-use crate::BrrrCommon;
+use crate::*;
 use anchor_lang::prelude::*;
-use vipers::{assert_keys_eq, validate::Validate};
+use anchor_lang::solana_program::program_option::COption;
+use vipers::{assert_keys_eq, invariant, validate::Validate};
 
 pub(crate) mod burn_cash;
+pub(crate) mod burn_cash_metapool;
+pub(crate) mod burn_cash_multi;
+pub(crate) mod burn_cash_stablecoin;
+pub(crate) mod burn_cash_to_lp;
+pub(crate) mod burn_cash_to_underlying;
+pub(crate) mod burn_cash_yield_bearing;
+pub(crate) mod cancel_redemption;
+pub(crate) mod enqueue_redemption;
+pub(crate) mod fill_redemptions;
+pub(crate) mod flash_mint;
+pub(crate) mod multi;
+pub(crate) mod new_redemption_queue;
 pub(crate) mod print_cash;
+pub(crate) mod print_cash_from_lp;
+pub(crate) mod print_cash_from_underlying;
+pub(crate) mod print_cash_metapool;
+pub(crate) mod print_cash_multi;
+pub(crate) mod print_cash_stablecoin;
+pub(crate) mod print_cash_yield_bearing;
 
-impl<'info> Validate<'info> for BrrrCommon<'info> {
+/// Accounts shared by every print and burn instruction, along with the
+/// [PricingAccounts] of the [Collateral].
+pub(crate) struct CashAccounts<'a, 'info, P: PricingAccounts<'info>> {
+    pub(crate) bank: &'a Account<'info, Bank>,
+    pub(crate) collateral: &'a Account<'info, Collateral>,
+    pub(crate) crate_token: &'a Account<'info, crate_token::CrateToken>,
+    pub(crate) crate_mint: &'a Account<'info, Mint>,
+    pub(crate) crate_collateral_tokens: &'a Account<'info, TokenAccount>,
+    pub(crate) pricing: &'a P,
+    pub(crate) token_program: &'a Program<'info, Token>,
+    pub(crate) crate_token_program: &'a Program<'info, crate_token::program::CrateToken>,
+}
+
+impl<'a, 'info, P: PricingAccounts<'info>> CashAccounts<'a, 'info, P> {
     fn validate(&self) -> Result<()> {
-        assert_keys_eq!(self.bank, self.collateral.bank);
-        assert_keys_eq!(self.bank.crate_mint, self.crate_mint);
-        assert_keys_eq!(self.crate_token, self.crate_collateral_tokens.owner);
-        assert_keys_eq!(self.crate_mint, self.crate_token.mint);
+        assert_keys_eq!(self.bank.key(), self.collateral.bank);
+        invariant!(!self.bank.paused, BankPaused);
+        assert_keys_eq!(self.bank.crate_mint, self.crate_mint.key());
+        assert_keys_eq!(self.crate_token.key(), self.crate_collateral_tokens.owner);
+        assert_keys_eq!(self.crate_mint.key(), self.crate_token.mint);
         assert_keys_eq!(self.crate_collateral_tokens.mint, self.collateral.mint);
 
-        // saber swap
-        self.saber_swap.validate()?;
-        assert_keys_eq!(self.collateral.mint, self.saber_swap.arrow.mint);
+        // pricing
+        require!(self.collateral.kind == P::KIND, CollateralKindMismatch);
+        self.pricing.validate()?;
+        self.pricing.validate_collateral(self.collateral)?;
+
+        Ok(())
+    }
+}
+
+/// Checks that `authority` may move tokens out of `source`,
+/// either as its owner or as its approved delegate.
+pub(crate) fn validate_source_authority(authority: Pubkey, source: &TokenAccount) -> Result<()> {
+    require!(
+        source.owner == authority || source.delegate == COption::Some(authority),
+        UnauthorizedSourceAuthority
+    );
+    Ok(())
+}
 
+/// Checks that `authority` may move `amount` tokens out of `source`.
+/// Delegates must be approved for at least `amount`.
+pub(crate) fn assert_source_allowance(
+    authority: Pubkey,
+    source: &TokenAccount,
+    amount: u64,
+) -> Result<()> {
+    if source.owner != authority {
+        require!(source.delegated_amount >= amount, DelegatedAmountExceeded);
+    }
+    Ok(())
+}
+
+impl<'info> BrrrCommon<'info> {
+    pub(crate) fn cash_accounts(&self) -> CashAccounts<'_, 'info, SaberSwapAccounts<'info>> {
+        self.cash_accounts_with(&self.saber_swap)
+    }
+
+    /// Uses different [PricingAccounts] for the Saber LP, e.g. those of a metapool.
+    pub(crate) fn cash_accounts_with<'a, P: PricingAccounts<'info>>(
+        &'a self,
+        pricing: &'a P,
+    ) -> CashAccounts<'a, 'info, P> {
+        CashAccounts {
+            bank: &self.bank,
+            collateral: &self.collateral,
+            crate_token: &self.crate_token,
+            crate_mint: &self.crate_mint,
+            crate_collateral_tokens: &self.crate_collateral_tokens,
+            pricing,
+            token_program: &self.token_program,
+            crate_token_program: &self.crate_token_program,
+        }
+    }
+}
+
+impl<'info> Validate<'info> for BrrrCommon<'info> {
+    fn validate(&self) -> Result<()> {
+        self.cash_accounts().validate()
+    }
+}
+
+impl<'info> BrrrStablecoinCommon<'info> {
+    pub(crate) fn cash_accounts(&self) -> CashAccounts<'_, 'info, StablecoinAccounts<'info>> {
+        CashAccounts {
+            bank: &self.bank,
+            collateral: &self.collateral,
+            crate_token: &self.crate_token,
+            crate_mint: &self.crate_mint,
+            crate_collateral_tokens: &self.crate_collateral_tokens,
+            pricing: &self.stablecoin,
+            token_program: &self.token_program,
+            crate_token_program: &self.crate_token_program,
+        }
+    }
+}
+
+impl<'info> Validate<'info> for BrrrStablecoinCommon<'info> {
+    fn validate(&self) -> Result<()> {
+        self.cash_accounts().validate()
+    }
+}
+
+impl<'info> BrrrYieldBearingCommon<'info> {
+    pub(crate) fn cash_accounts(&self) -> CashAccounts<'_, 'info, YieldBearingAccounts<'info>> {
+        CashAccounts {
+            bank: &self.bank,
+            collateral: &self.collateral,
+            crate_token: &self.crate_token,
+            crate_mint: &self.crate_mint,
+            crate_collateral_tokens: &self.crate_collateral_tokens,
+            pricing: &self.yield_bearing,
+            token_program: &self.token_program,
+            crate_token_program: &self.crate_token_program,
+        }
+    }
+}
+
+impl<'info> Validate<'info> for BrrrYieldBearingCommon<'info> {
+    fn validate(&self) -> Result<()> {
+        self.cash_accounts().validate()
+    }
+}
+
+impl<'info> Validate<'info> for BrrrMultiCommon<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.bank.crate_mint, self.crate_mint.key());
+        assert_keys_eq!(self.bank.crate_token, self.crate_token.key());
+        assert_keys_eq!(self.crate_mint.key(), self.crate_token.mint);
         Ok(())
     }
 }
@@ -0,0 +1,132 @@
+//! Parsing of [Collateral] account groups for batch printing and burning.
+
+use crate::*;
+use anchor_lang::prelude::*;
+use bankman::CollateralKind;
+use vipers::invariant;
+
+use super::CashAccounts;
+
+/// [PricingAccounts] of a [Collateral] in a group, by [CollateralKind].
+pub(crate) enum GroupPricing<'info> {
+    /// A [CollateralKind::SaberLpArrow].
+    Saber(SaberSwapAccounts<'info>),
+    /// A [CollateralKind::Stablecoin].
+    Stablecoin(StablecoinAccounts<'info>),
+    /// A [CollateralKind::YieldBearing].
+    YieldBearing(YieldBearingAccounts<'info>),
+}
+
+/// The accounts of a single [Collateral] in a batch instruction.
+///
+/// A group is laid out as `[collateral, crate_collateral_tokens, ...token_accounts, ...pricing]`,
+/// where the pricing accounts are:
+/// - [CollateralKind::SaberLpArrow]: `[arrow, saber_swap, pool_mint, reserve_a, reserve_b]`
+/// - [CollateralKind::Stablecoin]: `[mint]`
+/// - [CollateralKind::YieldBearing]: `[mint, reserve]`
+pub(crate) struct CollateralGroup<'info> {
+    pub(crate) collateral: Account<'info, Collateral>,
+    pub(crate) crate_collateral_tokens: Account<'info, TokenAccount>,
+    /// Instruction-specific token accounts of the user.
+    pub(crate) token_accounts: Vec<Account<'info, TokenAccount>>,
+    pub(crate) pricing: GroupPricing<'info>,
+}
+
+/// Iterates over the [CollateralGroup]s in the remaining accounts.
+pub(crate) struct CollateralGroups<'a, 'info> {
+    remaining_accounts: &'a [AccountInfo<'info>],
+    num_token_accounts: usize,
+}
+
+impl<'a, 'info> CollateralGroups<'a, 'info> {
+    pub(crate) fn new(
+        remaining_accounts: &'a [AccountInfo<'info>],
+        num_token_accounts: usize,
+    ) -> Self {
+        Self {
+            remaining_accounts,
+            num_token_accounts,
+        }
+    }
+
+    /// Parses the next group.
+    pub(crate) fn next_group(&mut self) -> Result<CollateralGroup<'info>> {
+        let accounts = self.take(2 + self.num_token_accounts)?;
+        let collateral: Account<Collateral> = Account::try_from(&accounts[0])?;
+        invariant!(accounts[1..].iter().all(|info| info.is_writable));
+        let crate_collateral_tokens = Account::try_from(&accounts[1])?;
+        let token_accounts = accounts[2..]
+            .iter()
+            .map(Account::try_from)
+            .collect::<Result<Vec<_>>>()?;
+
+        let pricing = match collateral.kind {
+            CollateralKind::SaberLpArrow => {
+                let pricing = self.take(5)?;
+                GroupPricing::Saber(SaberSwapAccounts {
+                    arrow: Box::new(Account::try_from(&pricing[0])?),
+                    saber_swap: Box::new(Account::try_from(&pricing[1])?),
+                    pool_mint: Box::new(Account::try_from(&pricing[2])?),
+                    reserve_a: Box::new(Account::try_from(&pricing[3])?),
+                    reserve_b: Box::new(Account::try_from(&pricing[4])?),
+                })
+            }
+            CollateralKind::Stablecoin => {
+                let pricing = self.take(1)?;
+                GroupPricing::Stablecoin(StablecoinAccounts {
+                    mint: Box::new(Account::try_from(&pricing[0])?),
+                })
+            }
+            CollateralKind::YieldBearing => {
+                let pricing = self.take(2)?;
+                GroupPricing::YieldBearing(YieldBearingAccounts {
+                    mint: Box::new(Account::try_from(&pricing[0])?),
+                    reserve: UncheckedAccount::try_from(pricing[1].clone()),
+                })
+            }
+        };
+
+        Ok(CollateralGroup {
+            collateral,
+            crate_collateral_tokens,
+            token_accounts,
+            pricing,
+        })
+    }
+
+    /// Checks that every remaining account has been consumed.
+    pub(crate) fn finish(&self) -> Result<()> {
+        invariant!(self.remaining_accounts.is_empty(), InvalidCollateralGroups);
+        Ok(())
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'a [AccountInfo<'info>]> {
+        invariant!(
+            self.remaining_accounts.len() >= len,
+            InvalidCollateralGroups
+        );
+        let (taken, rest) = self.remaining_accounts.split_at(len);
+        self.remaining_accounts = rest;
+        Ok(taken)
+    }
+}
+
+impl<'info> BrrrMultiCommon<'info> {
+    /// Builds the [CashAccounts] of a [CollateralGroup].
+    pub(crate) fn cash_accounts<'a, P: PricingAccounts<'info>>(
+        &'a self,
+        group: &'a CollateralGroup<'info>,
+        pricing: &'a P,
+    ) -> CashAccounts<'a, 'info, P> {
+        CashAccounts {
+            bank: &self.bank,
+            collateral: &group.collateral,
+            crate_token: &self.crate_token,
+            crate_mint: &self.crate_mint,
+            crate_collateral_tokens: &group.crate_collateral_tokens,
+            pricing,
+            token_program: &self.token_program,
+            crate_token_program: &self.crate_token_program,
+        }
+    }
+}
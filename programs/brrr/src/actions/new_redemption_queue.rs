@@ -0,0 +1,32 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, invariant, unwrap_bump, validate::Validate};
+
+/// Creates a [RedemptionQueue].
+pub fn new_redemption_queue(ctx: Context<NewRedemptionQueue>) -> Result<()> {
+    let queue = &mut ctx.accounts.queue;
+    queue.collateral = ctx.accounts.collateral.key();
+    queue.bump = unwrap_bump!(ctx, "queue");
+    queue.escrow = ctx.accounts.escrow.key();
+    queue.head = 0;
+    queue.tail = 0;
+
+    emit!(NewRedemptionQueueEvent {
+        queue: queue.key(),
+        collateral: queue.collateral,
+        timestamp: Clock::get()?.unix_timestamp
+    });
+
+    Ok(())
+}
+
+impl<'info> Validate<'info> for NewRedemptionQueue<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.collateral.bank, self.bank);
+        assert_keys_eq!(self.escrow.owner, self.queue);
+        assert_keys_eq!(self.escrow.mint, self.bank.crate_mint);
+        invariant!(self.escrow.delegate.is_none());
+        invariant!(self.escrow.close_authority.is_none());
+        Ok(())
+    }
+}
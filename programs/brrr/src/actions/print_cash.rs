@@ -1,67 +1,90 @@
-use std::convert::TryInto;
-
 use crate::*;
 use anchor_lang::prelude::*;
-use converter::CashSwap;
+use converter::{CollateralPricer, Rounding};
 use vipers::{assert_keys_eq, unwrap_int, validate::Validate};
 
+use super::{assert_source_allowance, validate_source_authority, CashAccounts};
+
 /// Prints $CASH.
 pub fn print_cash(ctx: Context<PrintCash>, deposit_amount: u64) -> Result<()> {
     ctx.accounts.print_cash(deposit_amount)
 }
 
 impl<'info> PrintCash<'info> {
-    fn print_cash(&self, deposit_amount: u64) -> Result<()> {
-        let current_balance = self.common.crate_collateral_tokens.amount;
+    pub(crate) fn print_cash(&self, deposit_amount: u64) -> Result<()> {
+        self.common.cash_accounts().print_cash(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+            deposit_amount,
+        )
+    }
+}
+
+impl<'a, 'info, P: PricingAccounts<'info>> CashAccounts<'a, 'info, P> {
+    /// Deposits `deposit_amount` collateral tokens and prints their value in $CASH.
+    pub(crate) fn print_cash(
+        &self,
+        depositor: &Signer<'info>,
+        depositor_source: &Account<'info, TokenAccount>,
+        mint_destination: &Account<'info, TokenAccount>,
+        issue_authority: &UncheckedAccount<'info>,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        assert_source_allowance(depositor.key(), depositor_source, deposit_amount)?;
+        let current_balance = self.crate_collateral_tokens.amount;
         require!(
-            unwrap_int!(current_balance.checked_add(deposit_amount))
-                <= self.common.collateral.hard_cap,
+            unwrap_int!(current_balance.checked_add(deposit_amount)) <= self.collateral.hard_cap,
             CollateralHardCapHit
         );
 
-        let swap: CashSwap = (&self.common.saber_swap).try_into()?;
-        let print_amount = unwrap_int!(swap.calculate_cash_for_pool_tokens(deposit_amount));
+        let pricer = self.pricing.pricer()?;
+        let print_amount = unwrap_int!(pricer.cash_for_collateral(deposit_amount, Rounding::Down));
         if print_amount == 0 {
             return Ok(());
         }
 
-        // transfer LP tokens to the crate
+        // transfer collateral tokens to the crate
         anchor_spl::token::transfer(
             CpiContext::new(
-                self.common.token_program.to_account_info(),
+                self.token_program.to_account_info(),
                 anchor_spl::token::Transfer {
-                    from: self.depositor_source.to_account_info(),
-                    to: self.common.crate_collateral_tokens.to_account_info(),
-                    authority: self.depositor.to_account_info(),
+                    from: depositor_source.to_account_info(),
+                    to: self.crate_collateral_tokens.to_account_info(),
+                    authority: depositor.to_account_info(),
                 },
             ),
             deposit_amount,
         )?;
 
         // issue new crate tokens
-        crate_token::cpi::issue(
-            CpiContext::new_with_signer(
-                self.common.crate_token_program.to_account_info(),
-                crate_token::cpi::accounts::Issue {
-                    crate_token: self.common.crate_token.to_account_info(),
-                    crate_mint: self.common.crate_mint.to_account_info(),
-                    issue_authority: self.issue_authority.to_account_info(),
-                    mint_destination: self.mint_destination.to_account_info(),
+        let authority = BankAuthority::issue(self.bank.key(), issue_authority.key())?;
+        authority.with_signer_seeds(|signer_seeds| {
+            crate_token::cpi::issue(
+                CpiContext::new_with_signer(
+                    self.crate_token_program.to_account_info(),
+                    crate_token::cpi::accounts::Issue {
+                        crate_token: self.crate_token.to_account_info(),
+                        crate_mint: self.crate_mint.to_account_info(),
+                        issue_authority: issue_authority.to_account_info(),
+                        mint_destination: mint_destination.to_account_info(),
 
-                    // there are no author/protocol fees, so we pass in garbage here
-                    author_fee_destination: self.mint_destination.to_account_info(),
-                    protocol_fee_destination: self.mint_destination.to_account_info(),
+                        // there are no author/protocol fees, so we pass in garbage here
+                        author_fee_destination: mint_destination.to_account_info(),
+                        protocol_fee_destination: mint_destination.to_account_info(),
 
-                    token_program: self.common.token_program.to_account_info(),
-                },
-                ISSUE_AUTHORITY_SIGNER_SEEDS,
-            ),
-            print_amount,
-        )?;
+                        token_program: self.token_program.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                print_amount,
+            )
+        })?;
 
         emit!(PrintCashEvent {
-            depositor: self.depositor.key(),
-            collateral_mint: self.common.crate_collateral_tokens.mint,
+            depositor: depositor.key(),
+            collateral_mint: self.crate_collateral_tokens.mint,
             deposit_amount,
             print_amount,
             timestamp: Clock::get()?.unix_timestamp
@@ -69,15 +92,31 @@ impl<'info> PrintCash<'info> {
 
         Ok(())
     }
+
+    /// Validates the accounts of a print instruction.
+    pub(crate) fn validate_print(
+        &self,
+        depositor: &Signer<'info>,
+        depositor_source: &Account<'info, TokenAccount>,
+        mint_destination: &Account<'info, TokenAccount>,
+        issue_authority: &UncheckedAccount<'info>,
+    ) -> Result<()> {
+        self.validate()?;
+        validate_source_authority(depositor.key(), depositor_source)?;
+        assert_keys_eq!(depositor_source.mint, self.collateral.mint);
+        assert_keys_eq!(mint_destination.mint, self.crate_token.mint);
+        assert_keys_eq!(issue_authority.key(), self.crate_token.issue_authority);
+        Ok(())
+    }
 }
 
 impl<'info> Validate<'info> for PrintCash<'info> {
     fn validate(&self) -> Result<()> {
-        self.common.validate()?;
-        assert_keys_eq!(self.depositor, self.depositor_source.owner);
-        assert_keys_eq!(self.depositor_source.mint, self.common.collateral.mint);
-        assert_keys_eq!(self.mint_destination.mint, self.common.crate_token.mint);
-        assert_keys_eq!(self.issue_authority, ISSUE_AUTHORITY_ADDRESS);
-        Ok(())
+        self.common.cash_accounts().validate_print(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+        )
     }
 }
@@ -0,0 +1,34 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, validate::Validate};
+
+/// Prints $CASH with raw Saber LP tokens.
+pub fn print_cash_from_lp(ctx: Context<PrintCashFromLp>, deposit_amount: u64) -> Result<()> {
+    ctx.accounts.print_cash_from_lp(deposit_amount)
+}
+
+impl<'info> PrintCashFromLp<'info> {
+    pub(crate) fn print_cash_from_lp(&self, deposit_amount: u64) -> Result<()> {
+        let print_cash = &self.print_cash;
+        self.arrow_stake.stake(
+            &print_cash.common.saber_swap.arrow,
+            &print_cash.depositor,
+            &self.depositor_lp_tokens,
+            &print_cash.depositor_source,
+            &print_cash.common.token_program,
+            deposit_amount,
+        )?;
+        print_cash.print_cash(deposit_amount)
+    }
+}
+
+impl<'info> Validate<'info> for PrintCashFromLp<'info> {
+    fn validate(&self) -> Result<()> {
+        self.print_cash.validate()?;
+        let arrow = &self.print_cash.common.saber_swap.arrow;
+        self.arrow_stake.validate_arrow(arrow)?;
+        assert_keys_eq!(self.depositor_lp_tokens.owner, self.print_cash.depositor);
+        assert_keys_eq!(self.depositor_lp_tokens.mint, arrow.vendor_miner.mint);
+        Ok(())
+    }
+}
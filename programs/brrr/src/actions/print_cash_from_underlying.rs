@@ -0,0 +1,70 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{assert_keys_eq, unwrap_int, validate::Validate};
+
+/// Prints $CASH with the underlying tokens of a Saber pool.
+pub fn print_cash_from_underlying(
+    ctx: Context<PrintCashFromUnderlying>,
+    token_a_amount: u64,
+    token_b_amount: u64,
+    min_print_amount: u64,
+) -> Result<()> {
+    ctx.accounts
+        .print_cash_from_underlying(token_a_amount, token_b_amount, min_print_amount)
+}
+
+impl<'info> PrintCashFromUnderlying<'info> {
+    fn print_cash_from_underlying(
+        &mut self,
+        token_a_amount: u64,
+        token_b_amount: u64,
+        min_print_amount: u64,
+    ) -> Result<()> {
+        let from_lp = &self.print_cash_from_lp;
+        let lp_balance_before = from_lp.depositor_lp_tokens.amount;
+        let cash_balance_before = from_lp.print_cash.mint_destination.amount;
+        self.saber.deposit(
+            &from_lp.print_cash.common.saber_swap,
+            &from_lp.print_cash.depositor,
+            &self.depositor_token_a,
+            &self.depositor_token_b,
+            &from_lp.depositor_lp_tokens,
+            &from_lp.print_cash.common.token_program,
+            token_a_amount,
+            token_b_amount,
+        )?;
+
+        // price the LP using the pool state after the deposit
+        let from_lp = &mut self.print_cash_from_lp;
+        from_lp.print_cash.common.saber_swap.reload()?;
+        from_lp.depositor_lp_tokens.reload()?;
+        let lp_amount = unwrap_int!(from_lp
+            .depositor_lp_tokens
+            .amount
+            .checked_sub(lp_balance_before));
+        from_lp.print_cash_from_lp(lp_amount)?;
+
+        from_lp.print_cash.mint_destination.reload()?;
+        let print_amount = unwrap_int!(from_lp
+            .print_cash
+            .mint_destination
+            .amount
+            .checked_sub(cash_balance_before));
+        require!(print_amount >= min_print_amount, PrintSlippageExceeded);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for PrintCashFromUnderlying<'info> {
+    fn validate(&self) -> Result<()> {
+        self.print_cash_from_lp.validate()?;
+        let print_cash = &self.print_cash_from_lp.print_cash;
+        let swap = &print_cash.common.saber_swap;
+        self.saber.validate_swap(swap)?;
+        assert_keys_eq!(self.depositor_token_a.owner, print_cash.depositor);
+        assert_keys_eq!(self.depositor_token_a.mint, swap.saber_swap.token_a.mint);
+        assert_keys_eq!(self.depositor_token_b.owner, print_cash.depositor);
+        assert_keys_eq!(self.depositor_token_b.mint, swap.saber_swap.token_b.mint);
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::metapool::SaberMetapoolAccounts;
+use crate::*;
+use anchor_lang::prelude::*;
+
+/// Prints $CASH with the Arrow LP of a Saber metapool.
+pub fn print_cash_metapool<'info>(
+    ctx: Context<'_, '_, '_, 'info, PrintCash<'info>>,
+    deposit_amount: u64,
+) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let metapool =
+        SaberMetapoolAccounts::load(&accounts.common.saber_swap, ctx.remaining_accounts)?;
+    let cash_accounts = accounts.common.cash_accounts_with(&metapool);
+    cash_accounts.validate_print(
+        &accounts.depositor,
+        &accounts.depositor_source,
+        &accounts.mint_destination,
+        &accounts.issue_authority,
+    )?;
+    cash_accounts.print_cash(
+        &accounts.depositor,
+        &accounts.depositor_source,
+        &accounts.mint_destination,
+        &accounts.issue_authority,
+        deposit_amount,
+    )
+}
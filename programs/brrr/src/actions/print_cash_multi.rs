@@ -0,0 +1,75 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::{unwrap_int, validate::Validate};
+
+use super::multi::{CollateralGroup, CollateralGroups, GroupPricing};
+
+/// Number of user token accounts in each group of [brrr::print_cash_multi]: `[depositor_source]`.
+pub const PRINT_CASH_MULTI_TOKEN_ACCOUNTS: usize = 1;
+
+/// Prints $CASH with several collaterals.
+pub fn print_cash_multi<'info>(
+    ctx: Context<'_, '_, '_, 'info, PrintCashMulti<'info>>,
+    deposit_amounts: Vec<u64>,
+    min_print_amount: u64,
+) -> Result<()> {
+    let accounts = ctx.accounts;
+    let cash_balance_before = accounts.mint_destination.amount;
+
+    let mut groups = CollateralGroups::new(ctx.remaining_accounts, PRINT_CASH_MULTI_TOKEN_ACCOUNTS);
+    for deposit_amount in deposit_amounts {
+        let group = groups.next_group()?;
+        match &group.pricing {
+            GroupPricing::Saber(pricing) => {
+                accounts.print_group(&group, pricing, deposit_amount)?
+            }
+            GroupPricing::Stablecoin(pricing) => {
+                accounts.print_group(&group, pricing, deposit_amount)?
+            }
+            GroupPricing::YieldBearing(pricing) => {
+                accounts.print_group(&group, pricing, deposit_amount)?
+            }
+        }
+    }
+    groups.finish()?;
+
+    accounts.mint_destination.reload()?;
+    let print_amount = unwrap_int!(accounts
+        .mint_destination
+        .amount
+        .checked_sub(cash_balance_before));
+    require!(print_amount >= min_print_amount, PrintSlippageExceeded);
+    Ok(())
+}
+
+impl<'info> PrintCashMulti<'info> {
+    fn print_group<P: PricingAccounts<'info>>(
+        &self,
+        group: &CollateralGroup<'info>,
+        pricing: &P,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        let cash_accounts = self.common.cash_accounts(group, pricing);
+        let depositor_source = &group.token_accounts[0];
+        cash_accounts.validate_print(
+            &self.depositor,
+            depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+        )?;
+        cash_accounts.print_cash(
+            &self.depositor,
+            depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+            deposit_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for PrintCashMulti<'info> {
+    fn validate(&self) -> Result<()> {
+        // each group is validated as it is printed
+        self.common.validate()
+    }
+}
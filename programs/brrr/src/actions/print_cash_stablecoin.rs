@@ -0,0 +1,31 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::validate::Validate;
+
+/// Prints $CASH with a stablecoin.
+pub fn print_cash_stablecoin(ctx: Context<PrintCashStablecoin>, deposit_amount: u64) -> Result<()> {
+    ctx.accounts.print_cash(deposit_amount)
+}
+
+impl<'info> PrintCashStablecoin<'info> {
+    fn print_cash(&self, deposit_amount: u64) -> Result<()> {
+        self.common.cash_accounts().print_cash(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+            deposit_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for PrintCashStablecoin<'info> {
+    fn validate(&self) -> Result<()> {
+        self.common.cash_accounts().validate_print(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+        )
+    }
+}
@@ -0,0 +1,34 @@
+use crate::*;
+use anchor_lang::prelude::*;
+use vipers::validate::Validate;
+
+/// Prints $CASH with a yield-bearing token.
+pub fn print_cash_yield_bearing(
+    ctx: Context<PrintCashYieldBearing>,
+    deposit_amount: u64,
+) -> Result<()> {
+    ctx.accounts.print_cash(deposit_amount)
+}
+
+impl<'info> PrintCashYieldBearing<'info> {
+    fn print_cash(&self, deposit_amount: u64) -> Result<()> {
+        self.common.cash_accounts().print_cash(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+            deposit_amount,
+        )
+    }
+}
+
+impl<'info> Validate<'info> for PrintCashYieldBearing<'info> {
+    fn validate(&self) -> Result<()> {
+        self.common.cash_accounts().validate_print(
+            &self.depositor,
+            &self.depositor_source,
+            &self.mint_destination,
+            &self.issue_authority,
+        )
+    }
+}
@@ -1,10 +1,25 @@
 //! Issue authority/withdraw authority addresses.
+//!
+//! Each [bankman::Bank] has its own issue and withdraw authorities, which are PDAs
+//! seeded by the key of the [bankman::Bank]. The original $CASH Bank, [LEGACY_BANK],
+//! instead uses the legacy [ISSUE_AUTHORITY_ADDRESS] and [WITHDRAW_AUTHORITY_ADDRESS],
+//! as the authorities of its Crate cannot be changed.
 #![deny(missing_docs)]
 
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program;
+use vipers::assert_keys_eq;
 
-/// Address of the issue authority to use for this Crate.
+pub use bankman::addresses::{
+    find_issue_authority_address, find_withdraw_authority_address, ISSUE_AUTHORITY_SEED,
+    WITHDRAW_AUTHORITY_SEED,
+};
+
+/// The original $CASH [bankman::Bank], the only one using the legacy authorities.
+pub static LEGACY_BANK: Pubkey =
+    static_pubkey::static_pubkey!("Em1PdaWY1NSpyGgKUstvZu3HzJNe9d15c3dePzBr9QwM");
+
+/// Address of the legacy issue authority of the [LEGACY_BANK].
 pub static ISSUE_AUTHORITY_ADDRESS: Pubkey =
     static_pubkey::static_pubkey!("BJ9L3jNu6tvrUxPHTMfwyA8Lgw2X6ky5bVNyDqiXSxgA");
 
@@ -15,7 +30,7 @@ pub const ISSUE_AUTHORITY_ADDRESS_BUMP: u8 = 255;
 pub static ISSUE_AUTHORITY_SIGNER_SEEDS: &[&[&[u8]]] =
     &[&[b"print", &[ISSUE_AUTHORITY_ADDRESS_BUMP]]];
 
-/// Address of the withdraw authority to use for this Crate.
+/// Address of the legacy withdraw authority of the [LEGACY_BANK].
 pub static WITHDRAW_AUTHORITY_ADDRESS: Pubkey =
     static_pubkey::static_pubkey!("7Twx9JYz3gB4rF3h2cyUMnQWj9QEtmwviTvVD7xjAGEw");
 
@@ -26,6 +41,64 @@ pub const WITHDRAW_AUTHORITY_ADDRESS_BUMP: u8 = 255;
 pub static WITHDRAW_AUTHORITY_SIGNER_SEEDS: &[&[&[u8]]] =
     &[&[b"burn", &[WITHDRAW_AUTHORITY_ADDRESS_BUMP]]];
 
+/// The issue or withdraw authority of a [bankman::Bank], which brrr signs for.
+pub(crate) struct BankAuthority {
+    seed: &'static [u8],
+    /// The [bankman::Bank], or [None] if this is a legacy authority.
+    bank: Option<Pubkey>,
+    bump: u8,
+}
+
+impl BankAuthority {
+    /// The issue authority `address` of the `bank`.
+    pub(crate) fn issue(bank: Pubkey, address: Pubkey) -> Result<Self> {
+        if bank == LEGACY_BANK && address == ISSUE_AUTHORITY_ADDRESS {
+            return Ok(Self {
+                seed: ISSUE_AUTHORITY_SEED,
+                bank: None,
+                bump: ISSUE_AUTHORITY_ADDRESS_BUMP,
+            });
+        }
+        let (expected, bump) = find_issue_authority_address(&bank);
+        assert_keys_eq!(address, expected, crate::ErrorCode::InvalidBankAuthority);
+        Ok(Self {
+            seed: ISSUE_AUTHORITY_SEED,
+            bank: Some(bank),
+            bump,
+        })
+    }
+
+    /// The withdraw authority `address` of the `bank`.
+    pub(crate) fn withdraw(bank: Pubkey, address: Pubkey) -> Result<Self> {
+        if bank == LEGACY_BANK && address == WITHDRAW_AUTHORITY_ADDRESS {
+            return Ok(Self {
+                seed: WITHDRAW_AUTHORITY_SEED,
+                bank: None,
+                bump: WITHDRAW_AUTHORITY_ADDRESS_BUMP,
+            });
+        }
+        let (expected, bump) = find_withdraw_authority_address(&bank);
+        assert_keys_eq!(address, expected, crate::ErrorCode::InvalidBankAuthority);
+        Ok(Self {
+            seed: WITHDRAW_AUTHORITY_SEED,
+            bank: Some(bank),
+            bump,
+        })
+    }
+
+    /// Calls `f` with the signer seeds of the authority.
+    pub(crate) fn with_signer_seeds<T>(
+        &self,
+        f: impl FnOnce(&[&[&[u8]]]) -> Result<T>,
+    ) -> Result<T> {
+        let bump = [self.bump];
+        match &self.bank {
+            Some(bank) => f(&[&[self.seed, bank.as_ref(), &bump]]),
+            None => f(&[&[self.seed, &bump]]),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,4 +115,36 @@ mod tests {
         assert_eq!(key, WITHDRAW_AUTHORITY_ADDRESS);
         assert_eq!(bump, WITHDRAW_AUTHORITY_ADDRESS_BUMP);
     }
+
+    #[test]
+    fn test_brrr_program_id() {
+        assert_eq!(bankman::addresses::BRRR_PROGRAM_ID, crate::ID);
+    }
+
+    #[test]
+    fn test_bank_authorities() {
+        let bank = Pubkey::new_unique();
+        let (issue_authority, _) = find_issue_authority_address(&bank);
+        let (withdraw_authority, _) = find_withdraw_authority_address(&bank);
+        assert_ne!(issue_authority, withdraw_authority);
+        assert_ne!(
+            issue_authority,
+            find_issue_authority_address(&Pubkey::new_unique()).0
+        );
+
+        assert!(BankAuthority::issue(bank, issue_authority).is_ok());
+        assert!(BankAuthority::issue(bank, ISSUE_AUTHORITY_ADDRESS).is_err());
+        assert!(BankAuthority::issue(bank, withdraw_authority).is_err());
+        assert!(BankAuthority::withdraw(bank, withdraw_authority).is_ok());
+        assert!(BankAuthority::withdraw(bank, WITHDRAW_AUTHORITY_ADDRESS).is_err());
+        assert!(BankAuthority::withdraw(bank, issue_authority).is_err());
+    }
+
+    #[test]
+    fn test_legacy_bank_authorities() {
+        assert!(BankAuthority::issue(LEGACY_BANK, ISSUE_AUTHORITY_ADDRESS).is_ok());
+        assert!(BankAuthority::withdraw(LEGACY_BANK, WITHDRAW_AUTHORITY_ADDRESS).is_ok());
+        assert!(BankAuthority::issue(LEGACY_BANK, WITHDRAW_AUTHORITY_ADDRESS).is_err());
+        assert!(BankAuthority::withdraw(LEGACY_BANK, ISSUE_AUTHORITY_ADDRESS).is_err());
+    }
 }
@@ -0,0 +1,145 @@
+//! Arrow staking adapters
+//!
+//! Allows $CASH to be printed from, and burned to, raw Saber LP tokens by
+//! wrapping and unwrapping them in their [Arrow] within the same instruction.
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use arrow_sunny::Arrow;
+use vipers::assert_keys_eq;
+
+use crate::{ArrowStakeAccounts, StakeAccounts};
+
+impl<'info> StakeAccounts<'info> {
+    fn to_cpi_accounts(&self) -> arrow_sunny::cpi::accounts::StakeCommon<'info> {
+        arrow_sunny::cpi::accounts::StakeCommon {
+            rewarder: self.rewarder.to_account_info(),
+            quarry: self.quarry.to_account_info(),
+            miner: self.miner.to_account_info(),
+            miner_vault: self.miner_vault.to_account_info(),
+        }
+    }
+}
+
+impl<'info> ArrowStakeAccounts<'info> {
+    /// Wraps `amount` Saber LP tokens of the depositor into Arrow tokens and stakes them.
+    pub(crate) fn stake(
+        &self,
+        arrow: &Account<'info, Arrow>,
+        depositor: &Signer<'info>,
+        depositor_lp_tokens: &Account<'info, TokenAccount>,
+        depositor_arrow_tokens: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        amount: u64,
+    ) -> Result<()> {
+        arrow_sunny::cpi::deposit_vendor(
+            CpiContext::new(
+                self.arrow_program.to_account_info(),
+                arrow_sunny::cpi::accounts::DepositVendor {
+                    arrow: arrow.to_account_info(),
+                    arrow_stake: self.arrow_stake_accounts(depositor, depositor_arrow_tokens),
+                    depositor_staked_tokens: depositor_lp_tokens.to_account_info(),
+                    vault_vendor_token_account: self.vault_vendor_token_account.to_account_info(),
+                    vendor_stake: self.vendor_stake.to_cpi_accounts(),
+                    pool: self.pool.to_account_info(),
+                    vault: self.vault.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    mine_program: self.mine_program.to_account_info(),
+                    sunny_program: self.sunny_program.to_account_info(),
+                    clock: self.clock.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+        arrow_sunny::cpi::stake_internal(CpiContext::new(
+            self.arrow_program.to_account_info(),
+            self.stake_internal_accounts(arrow, token_program),
+        ))
+    }
+
+    /// Unstakes `amount` Arrow tokens of the depositor and unwraps them into Saber LP tokens.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn unstake(
+        &self,
+        arrow: &Account<'info, Arrow>,
+        depositor: &Signer<'info>,
+        depositor_arrow_tokens: &Account<'info, TokenAccount>,
+        lp_destination: &Account<'info, TokenAccount>,
+        sunny_pool_fee_destination: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        amount: u64,
+    ) -> Result<()> {
+        arrow_sunny::cpi::unstake_internal(
+            CpiContext::new(
+                self.arrow_program.to_account_info(),
+                arrow_sunny::cpi::accounts::UnstakeInternal {
+                    arrow_stake: self.arrow_stake_accounts(depositor, depositor_arrow_tokens),
+                    stake: self.stake_internal_accounts(arrow, token_program),
+                },
+            ),
+            amount,
+        )?;
+        arrow_sunny::cpi::withdraw_vendor_tokens(
+            CpiContext::new(
+                self.arrow_program.to_account_info(),
+                arrow_sunny::cpi::accounts::WithdrawVendorTokens {
+                    stake: arrow_sunny::cpi::accounts::DepositVendor {
+                        arrow: arrow.to_account_info(),
+                        arrow_stake: self.arrow_stake_accounts(depositor, depositor_arrow_tokens),
+                        depositor_staked_tokens: lp_destination.to_account_info(),
+                        vault_vendor_token_account: self
+                            .vault_vendor_token_account
+                            .to_account_info(),
+                        vendor_stake: self.vendor_stake.to_cpi_accounts(),
+                        pool: self.pool.to_account_info(),
+                        vault: self.vault.to_account_info(),
+                        token_program: token_program.to_account_info(),
+                        mine_program: self.mine_program.to_account_info(),
+                        sunny_program: self.sunny_program.to_account_info(),
+                        clock: self.clock.to_account_info(),
+                    },
+                    sunny_pool_fee_destination: sunny_pool_fee_destination.to_account_info(),
+                },
+            ),
+            amount,
+        )
+    }
+
+    fn arrow_stake_accounts(
+        &self,
+        depositor: &Signer<'info>,
+        depositor_arrow_tokens: &Account<'info, TokenAccount>,
+    ) -> arrow_sunny::cpi::accounts::ArrowStake<'info> {
+        arrow_sunny::cpi::accounts::ArrowStake {
+            arrow_mint: self.arrow_mint.to_account_info(),
+            depositor: depositor.to_account_info(),
+            depositor_arrow_tokens: depositor_arrow_tokens.to_account_info(),
+        }
+    }
+
+    fn stake_internal_accounts(
+        &self,
+        arrow: &Account<'info, Arrow>,
+        token_program: &Program<'info, Token>,
+    ) -> arrow_sunny::cpi::accounts::StakeInternal<'info> {
+        arrow_sunny::cpi::accounts::StakeInternal {
+            arrow: arrow.to_account_info(),
+            internal_mint: self.internal_mint.to_account_info(),
+            vault_internal_token_account: self.vault_internal_token_account.to_account_info(),
+            internal_stake: self.internal_stake.to_cpi_accounts(),
+            pool: self.pool.to_account_info(),
+            vault: self.vault.to_account_info(),
+            token_program: token_program.to_account_info(),
+            mine_program: self.mine_program.to_account_info(),
+            sunny_program: self.sunny_program.to_account_info(),
+            clock: self.clock.to_account_info(),
+        }
+    }
+
+    /// Validates these accounts against the [Arrow] of the collateral.
+    /// The remaining accounts are validated by [arrow_sunny].
+    pub(crate) fn validate_arrow(&self, arrow: &Account<'info, Arrow>) -> Result<()> {
+        assert_keys_eq!(self.arrow_mint.key(), arrow.mint);
+        Ok(())
+    }
+}
@@ -38,3 +38,113 @@ pub struct BurnCashEvent {
     /// Timestamp of the event.
     pub timestamp: i64,
 }
+
+/// Emitted when $CASH is flash minted.
+#[event]
+pub struct FlashMintEvent {
+    /// Borrower
+    #[index]
+    pub borrower: Pubkey,
+    /// Program invoked with the flash minted $CASH.
+    #[index]
+    pub receiver_program: Pubkey,
+
+    /// Amount of $CASH flash minted.
+    pub amount: u64,
+    /// Fee burned in addition to the flash minted amount.
+    pub fee: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RedemptionQueue] is created.
+#[event]
+pub struct NewRedemptionQueueEvent {
+    /// The [crate::RedemptionQueue].
+    #[index]
+    pub queue: Pubkey,
+    /// The [bankman::Collateral] redeemed by the queue.
+    #[index]
+    pub collateral: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when $CASH is escrowed in a [crate::RedemptionQueue].
+#[event]
+pub struct EnqueueRedemptionEvent {
+    /// The [crate::RedemptionQueue].
+    #[index]
+    pub queue: Pubkey,
+    /// The [crate::RedemptionTicket].
+    pub ticket: Pubkey,
+    /// Owner of the ticket.
+    #[index]
+    pub owner: Pubkey,
+
+    /// Position of the ticket in the queue.
+    pub index: u64,
+    /// Amount of $CASH escrowed.
+    pub amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RedemptionTicket] is cancelled.
+#[event]
+pub struct CancelRedemptionEvent {
+    /// The [crate::RedemptionQueue].
+    #[index]
+    pub queue: Pubkey,
+    /// The [crate::RedemptionTicket].
+    pub ticket: Pubkey,
+    /// Owner of the ticket.
+    #[index]
+    pub owner: Pubkey,
+
+    /// Amount of $CASH returned.
+    pub amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RedemptionTicket] is filled, fully or partially.
+#[event]
+pub struct FillRedemptionEvent {
+    /// The [crate::RedemptionQueue].
+    #[index]
+    pub queue: Pubkey,
+    /// The [crate::RedemptionTicket].
+    pub ticket: Pubkey,
+    /// Owner of the ticket.
+    #[index]
+    pub owner: Pubkey,
+
+    /// Amount of $CASH burned.
+    pub burn_amount: u64,
+    /// Amount of collateral tokens withdrawn.
+    pub withdraw_amount: u64,
+    /// Amount of $CASH remaining in the ticket.
+    pub remaining_amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::RedemptionTicket] is skipped because its withdraw
+/// destination cannot receive the collateral.
+#[event]
+pub struct SkipRedemptionEvent {
+    /// The [crate::RedemptionQueue].
+    #[index]
+    pub queue: Pubkey,
+    /// The [crate::RedemptionTicket].
+    pub ticket: Pubkey,
+    /// Owner of the ticket.
+    #[index]
+    pub owner: Pubkey,
+
+    /// Amount of $CASH remaining in the ticket, which the owner may cancel.
+    pub remaining_amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
@@ -5,11 +5,19 @@
 //! Burning allows the redemption of any single Saber LP.
 #![deny(rustdoc::all)]
 #![allow(rustdoc::missing_doc_code_examples)]
+#![allow(clippy::result_large_err)]
 
 mod actions;
 mod addresses;
+mod arrow;
 mod events;
+mod macros;
+mod metapool;
+mod pricing;
+mod reserve;
 mod saber;
+mod stablecoin;
+mod state;
 
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
@@ -20,6 +28,10 @@ use vipers::validate::Validate;
 
 pub use addresses::*;
 pub use events::*;
+pub use metapool::{MAX_BASE_POOLS, MAX_POOL_RESERVES};
+pub use pricing::*;
+pub use reserve::*;
+pub use state::*;
 
 declare_id!("BRRRot6ig147TBU6EGp7TMesmQrwu729CbG6qu2ZUHWm");
 
@@ -51,6 +63,239 @@ pub mod brrr {
         vipers::invariant!(false, "temporarily disabled");
         actions::burn_cash::burn_cash(ctx, burn_amount)
     }
+
+    /// Flash mints $CASH.
+    ///
+    /// Issues `amount` $CASH to the `mint_destination`, invokes the `receiver_program`
+    /// with `data` and the remaining accounts, then burns `amount` plus the
+    /// [Bank::flash_mint_fee_bps] fee from the `repay_source`.
+    /// `amount` may not exceed the [Bank::flash_mint_ceiling].
+    #[access_control(ctx.accounts.validate())]
+    pub fn flash_mint<'info>(
+        ctx: Context<'_, '_, '_, 'info, FlashMint<'info>>,
+        amount: u64,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::flash_mint::flash_mint(ctx, amount, data)
+    }
+
+    /// Creates the [RedemptionQueue] of a [Collateral].
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_redemption_queue(ctx: Context<NewRedemptionQueue>) -> Result<()> {
+        actions::new_redemption_queue::new_redemption_queue(ctx)
+    }
+
+    /// Escrows `amount` $CASH at the back of a [RedemptionQueue],
+    /// to be redeemed once enough collateral is available.
+    #[access_control(ctx.accounts.validate())]
+    pub fn enqueue_redemption(ctx: Context<EnqueueRedemption>, amount: u64) -> Result<()> {
+        actions::enqueue_redemption::enqueue_redemption(ctx, amount)
+    }
+
+    /// Returns the unredeemed $CASH of a [RedemptionTicket] to its owner.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_redemption(ctx: Context<CancelRedemption>) -> Result<()> {
+        actions::cancel_redemption::cancel_redemption(ctx)
+    }
+
+    /// Fills a [RedemptionQueue] of a Saber LP [Collateral] in FIFO order,
+    /// using the collateral currently held by the crate.
+    ///
+    /// The tickets are passed as remaining accounts starting from the head of the queue,
+    /// in groups of `[ticket, withdraw_destination, owner]`.
+    #[access_control(ctx.accounts.validate())]
+    pub fn fill_redemptions_saber<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRedemptionsSaber<'info>>,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::fill_redemptions::fill_redemptions_saber(ctx)
+    }
+
+    /// Fills a [RedemptionQueue] of a stablecoin [Collateral] in FIFO order.
+    ///
+    /// The tickets are passed as in [brrr::fill_redemptions_saber].
+    #[access_control(ctx.accounts.validate())]
+    pub fn fill_redemptions_stablecoin<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRedemptionsStablecoin<'info>>,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::fill_redemptions::fill_redemptions_stablecoin(ctx)
+    }
+
+    /// Fills a [RedemptionQueue] of a yield-bearing [Collateral] in FIFO order.
+    ///
+    /// The tickets are passed as in [brrr::fill_redemptions_saber].
+    #[access_control(ctx.accounts.validate())]
+    pub fn fill_redemptions_yield_bearing<'info>(
+        ctx: Context<'_, '_, '_, 'info, FillRedemptionsYieldBearing<'info>>,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::fill_redemptions::fill_redemptions_yield_bearing(ctx)
+    }
+
+    /// Prints $CASH in exchange for several collaterals at once.
+    ///
+    /// Each collateral is passed as a group of remaining accounts,
+    /// `[collateral, crate_collateral_tokens, depositor_source, ...pricing]`,
+    /// one group per deposit amount. Fails if fewer than `min_print_amount`
+    /// $CASH are printed in total.
+    #[access_control(ctx.accounts.validate())]
+    pub fn print_cash_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrintCashMulti<'info>>,
+        deposit_amounts: Vec<u64>,
+        min_print_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_multi::print_cash_multi(ctx, deposit_amounts, min_print_amount)
+    }
+
+    /// Burns $CASH in exchange for several collaterals at once.
+    ///
+    /// Each collateral is passed as a group of remaining accounts,
+    /// `[collateral, crate_collateral_tokens, withdraw_destination, author_fee_destination,
+    /// protocol_fee_destination, ...pricing]`, one group per burn amount.
+    /// Fails if the collateral received is worth less than `min_withdraw_value` $CASH in total.
+    #[access_control(ctx.accounts.validate())]
+    pub fn burn_cash_multi<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnCashMulti<'info>>,
+        burn_amounts: Vec<u64>,
+        min_withdraw_value: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_multi::burn_cash_multi(ctx, burn_amounts, min_withdraw_value)
+    }
+
+    /// Prints $CASH in exchange for raw Saber LP tokens.
+    ///
+    /// The LP tokens are first staked into their [Arrow], and the resulting
+    /// Arrow tokens are deposited as in [brrr::print_cash].
+    #[access_control(ctx.accounts.validate())]
+    pub fn print_cash_from_lp(ctx: Context<PrintCashFromLp>, deposit_amount: u64) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_from_lp::print_cash_from_lp(ctx, deposit_amount)
+    }
+
+    /// Burns $CASH in exchange for raw Saber LP tokens.
+    ///
+    /// $CASH is burned for Arrow tokens as in [brrr::burn_cash], which are
+    /// then unstaked from their [Arrow] into Saber LP tokens.
+    #[access_control(ctx.accounts.validate())]
+    pub fn burn_cash_to_lp(ctx: Context<BurnCashToLp>, burn_amount: u64) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_to_lp::burn_cash_to_lp(ctx, burn_amount)
+    }
+
+    /// Prints $CASH in exchange for the underlying tokens of a Saber pool.
+    ///
+    /// The tokens are deposited into the Saber pool, and the resulting LP tokens
+    /// are deposited as in [brrr::print_cash_from_lp]. Fails if fewer than
+    /// `min_print_amount` $CASH are printed.
+    #[access_control(ctx.accounts.validate())]
+    pub fn print_cash_from_underlying(
+        ctx: Context<PrintCashFromUnderlying>,
+        token_a_amount: u64,
+        token_b_amount: u64,
+        min_print_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_from_underlying::print_cash_from_underlying(
+            ctx,
+            token_a_amount,
+            token_b_amount,
+            min_print_amount,
+        )
+    }
+
+    /// Burns $CASH in exchange for one of the underlying tokens of a Saber pool.
+    ///
+    /// $CASH is burned for Saber LP tokens as in [brrr::burn_cash_to_lp], which are
+    /// then withdrawn from the Saber pool as the token of the `underlying_destination`.
+    /// Fails if fewer than `min_withdraw_amount` tokens are withdrawn.
+    #[access_control(ctx.accounts.validate())]
+    pub fn burn_cash_to_underlying(
+        ctx: Context<BurnCashToUnderlying>,
+        burn_amount: u64,
+        min_withdraw_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_to_underlying::burn_cash_to_underlying(
+            ctx,
+            burn_amount,
+            min_withdraw_amount,
+        )
+    }
+
+    /// Prints $CASH in exchange for the Arrow LP of a Saber metapool.
+    ///
+    /// The base pools backing the metapool's reserves are passed as remaining accounts,
+    /// in variable-length groups of `[saber_swap, pool_mint, ...reserves]`.
+    /// Base LP tokens are valued at the virtual price of their pool.
+    pub fn print_cash_metapool<'info>(
+        ctx: Context<'_, '_, '_, 'info, PrintCash<'info>>,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_metapool::print_cash_metapool(ctx, deposit_amount)
+    }
+
+    /// Burns $CASH in exchange for the Arrow LP of a Saber metapool.
+    ///
+    /// The base pools are passed as in [brrr::print_cash_metapool].
+    pub fn burn_cash_metapool<'info>(
+        ctx: Context<'_, '_, '_, 'info, BurnCash<'info>>,
+        burn_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_metapool::burn_cash_metapool(ctx, burn_amount)
+    }
+
+    /// Prints $CASH in exchange for a plain stablecoin.
+    ///
+    /// The stablecoin is valued 1:1 with $CASH after decimal scaling.
+    #[access_control(ctx.accounts.validate())]
+    pub fn print_cash_stablecoin(
+        ctx: Context<PrintCashStablecoin>,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_stablecoin::print_cash_stablecoin(ctx, deposit_amount)
+    }
+
+    /// Burns $CASH in exchange for a plain stablecoin.
+    ///
+    /// The stablecoin is valued 1:1 with $CASH after decimal scaling.
+    #[access_control(ctx.accounts.validate())]
+    pub fn burn_cash_stablecoin(ctx: Context<BurnCashStablecoin>, burn_amount: u64) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_stablecoin::burn_cash_stablecoin(ctx, burn_amount)
+    }
+
+    /// Prints $CASH in exchange for a yield-bearing token.
+    ///
+    /// The token is valued at the exchange rate of its lending reserve,
+    /// which must have been refreshed in the current slot.
+    #[access_control(ctx.accounts.validate())]
+    pub fn print_cash_yield_bearing(
+        ctx: Context<PrintCashYieldBearing>,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::print_cash_yield_bearing::print_cash_yield_bearing(ctx, deposit_amount)
+    }
+
+    /// Burns $CASH in exchange for a yield-bearing token.
+    ///
+    /// The token is valued at the exchange rate of its lending reserve,
+    /// which must have been refreshed in the current slot.
+    #[access_control(ctx.accounts.validate())]
+    pub fn burn_cash_yield_bearing(
+        ctx: Context<BurnCashYieldBearing>,
+        burn_amount: u64,
+    ) -> Result<()> {
+        vipers::invariant!(false, "temporarily disabled");
+        actions::burn_cash_yield_bearing::burn_cash_yield_bearing(ctx, burn_amount)
+    }
 }
 
 /// Accounts related to the Saber pool.
@@ -68,6 +313,94 @@ pub struct SaberSwapAccounts<'info> {
     pub reserve_b: Box<Account<'info, TokenAccount>>,
 }
 
+/// Saber pool accounts modified when adding or removing liquidity.
+#[derive(Accounts)]
+pub struct SaberLiquidityAccounts<'info> {
+    /// The Saber swap program.
+    pub saber_program: Program<'info, stable_swap_anchor::StableSwap>,
+    /// Authority of the Saber swap.
+    /// CHECK: validated by Saber.
+    pub swap_authority: UncheckedAccount<'info>,
+    /// Mint of the pool.
+    #[account(mut)]
+    pub pool_mint: Box<Account<'info, Mint>>,
+    /// Reserve of token A.
+    #[account(mut)]
+    pub reserve_a: Box<Account<'info, TokenAccount>>,
+    /// Reserve of token B.
+    #[account(mut)]
+    pub reserve_b: Box<Account<'info, TokenAccount>>,
+}
+
+/// Quarry staking accounts of an [Arrow] miner.
+#[derive(Accounts)]
+pub struct StakeAccounts<'info> {
+    /// Rewarder.
+    /// CHECK: validated by [arrow_sunny].
+    pub rewarder: UncheckedAccount<'info>,
+    /// Quarry.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub quarry: UncheckedAccount<'info>,
+    /// Miner.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub miner: UncheckedAccount<'info>,
+    /// Miner vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub miner_vault: UncheckedAccount<'info>,
+}
+
+/// Accounts for staking Saber LP tokens into, and unstaking them from, an [Arrow].
+#[derive(Accounts)]
+pub struct ArrowStakeAccounts<'info> {
+    /// Mint of the [Arrow].
+    #[account(mut)]
+    pub arrow_mint: Box<Account<'info, Mint>>,
+    /// Saber LP tokens of the Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault_vendor_token_account: UncheckedAccount<'info>,
+    /// Vendor miner stake accounts.
+    pub vendor_stake: StakeAccounts<'info>,
+    /// Internal mint of the Sunny pool.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub internal_mint: UncheckedAccount<'info>,
+    /// Internal tokens of the Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault_internal_token_account: UncheckedAccount<'info>,
+    /// Internal miner stake accounts.
+    pub internal_stake: StakeAccounts<'info>,
+    /// Sunny pool.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub pool: UncheckedAccount<'info>,
+    /// Sunny vault.
+    /// CHECK: validated by [arrow_sunny].
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+    /// [arrow_sunny] program.
+    pub arrow_program: Program<'info, arrow_sunny::program::ArrowSunny>,
+    /// Quarry mine program.
+    /// CHECK: validated by [arrow_sunny].
+    pub mine_program: UncheckedAccount<'info>,
+    /// Sunny program.
+    /// CHECK: validated by [arrow_sunny].
+    pub sunny_program: UncheckedAccount<'info>,
+    /// Clock sysvar.
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Accounts related to a plain stablecoin.
+#[derive(Accounts)]
+pub struct StablecoinAccounts<'info> {
+    /// [Mint] of the stablecoin.
+    pub mint: Box<Account<'info, Mint>>,
+}
+
 /// Accounts for printing $CASH.
 #[derive(Accounts)]
 pub struct PrintCash<'info> {
@@ -75,6 +408,7 @@ pub struct PrintCash<'info> {
     pub common: BrrrCommon<'info>,
 
     /// The depositor into the pool.
+    /// This may be the owner or an approved delegate of the `depositor_source`.
     #[account(mut)]
     pub depositor: Signer<'info>,
 
@@ -86,7 +420,7 @@ pub struct PrintCash<'info> {
     #[account(mut)]
     pub mint_destination: Box<Account<'info, TokenAccount>>,
 
-    /// The [ISSUE_AUTHORITY_ADDRESS].
+    /// The issue authority of the [Bank]. See [find_issue_authority_address].
     /// CHECK: this is handled by Vipers.
     pub issue_authority: UncheckedAccount<'info>,
 }
@@ -126,7 +460,94 @@ pub struct BurnCash<'info> {
     /// Common accounts.
     pub common: BrrrCommon<'info>,
 
+    /// The burner of the $CASH.
+    /// This may be the owner or an approved delegate of the `burned_cash_source`.
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    /// The source of the burned $CASH.
+    #[account(mut)]
+    pub burned_cash_source: Box<Account<'info, TokenAccount>>,
+
+    /// Destination of the issued tokens.
+    #[account(mut)]
+    pub withdraw_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Author fee token destination
+    #[account(mut)]
+    pub author_fee_destination: Account<'info, TokenAccount>,
+
+    /// Protocol fee token destination
+    #[account(mut)]
+    pub protocol_fee_destination: Account<'info, TokenAccount>,
+
+    /// The withdraw authority of the [Bank]. See [find_withdraw_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub withdraw_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts common to printing and burning $CASH with a [bankman::CollateralKind::Stablecoin].
+#[derive(Accounts)]
+pub struct BrrrStablecoinCommon<'info> {
+    /// Information about the bank.
+    pub bank: Box<Account<'info, Bank>>,
+
+    /// The [Collateral].
+    pub collateral: Box<Account<'info, Collateral>>,
+
+    /// Information about the crate.
+    pub crate_token: Box<Account<'info, crate_token::CrateToken>>,
+
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Box<Account<'info, Mint>>,
+
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_collateral_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Stablecoin accounts.
+    pub stablecoin: StablecoinAccounts<'info>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+
+    /// [crate_token::program::CrateToken] program.
+    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
+}
+
+/// Accounts for printing $CASH with a stablecoin.
+#[derive(Accounts)]
+pub struct PrintCashStablecoin<'info> {
+    /// Common accounts.
+    pub common: BrrrStablecoinCommon<'info>,
+
     /// The depositor into the pool.
+    /// This may be the owner or an approved delegate of the `depositor_source`.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// The source of the deposited [Collateral] tokens.
+    #[account(mut)]
+    pub depositor_source: Box<Account<'info, TokenAccount>>,
+
+    /// Destination of the issued $CASH.
+    #[account(mut)]
+    pub mint_destination: Box<Account<'info, TokenAccount>>,
+
+    /// The issue authority of the [Bank]. See [find_issue_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub issue_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for burning $CASH for a stablecoin.
+#[derive(Accounts)]
+pub struct BurnCashStablecoin<'info> {
+    /// Common accounts.
+    pub common: BrrrStablecoinCommon<'info>,
+
+    /// The burner of the $CASH.
+    /// This may be the owner or an approved delegate of the `burned_cash_source`.
     #[account(mut)]
     pub burner: Signer<'info>,
 
@@ -146,11 +567,437 @@ pub struct BurnCash<'info> {
     #[account(mut)]
     pub protocol_fee_destination: Account<'info, TokenAccount>,
 
-    /// The [WITHDRAW_AUTHORITY_ADDRESS].
+    /// The withdraw authority of the [Bank]. See [find_withdraw_authority_address].
     /// CHECK: this is handled by Vipers.
     pub withdraw_authority: UncheckedAccount<'info>,
 }
 
+/// Accounts related to a yield-bearing token.
+#[derive(Accounts)]
+pub struct YieldBearingAccounts<'info> {
+    /// [Mint] of the yield-bearing token.
+    pub mint: Box<Account<'info, Mint>>,
+    /// The lending reserve which issues the token.
+    /// CHECK: checked against the [Collateral::reserve] and its lending program,
+    /// and parsed as a [ReserveState].
+    pub reserve: UncheckedAccount<'info>,
+}
+
+/// Accounts common to batch printing and burning $CASH.
+/// The accounts of each [Collateral] are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct BrrrMultiCommon<'info> {
+    /// Information about the bank.
+    pub bank: Box<Account<'info, Bank>>,
+
+    /// Information about the crate.
+    pub crate_token: Box<Account<'info, crate_token::CrateToken>>,
+
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Box<Account<'info, Mint>>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+
+    /// [crate_token::program::CrateToken] program.
+    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
+}
+
+/// Accounts for [brrr::print_cash_multi].
+#[derive(Accounts)]
+pub struct PrintCashMulti<'info> {
+    /// Common accounts.
+    pub common: BrrrMultiCommon<'info>,
+
+    /// The depositor into the pools.
+    /// This may be the owner or an approved delegate of each `depositor_source`.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// Destination of the issued $CASH.
+    #[account(mut)]
+    pub mint_destination: Box<Account<'info, TokenAccount>>,
+
+    /// The issue authority of the [Bank]. See [find_issue_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub issue_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for [brrr::burn_cash_multi].
+#[derive(Accounts)]
+pub struct BurnCashMulti<'info> {
+    /// Common accounts.
+    pub common: BrrrMultiCommon<'info>,
+
+    /// The burner of the $CASH.
+    /// This may be the owner or an approved delegate of the `burned_cash_source`.
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    /// The source of the burned $CASH.
+    #[account(mut)]
+    pub burned_cash_source: Box<Account<'info, TokenAccount>>,
+
+    /// The withdraw authority of the [Bank]. See [find_withdraw_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub withdraw_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for [brrr::flash_mint].
+#[derive(Accounts)]
+pub struct FlashMint<'info> {
+    /// Information about the bank.
+    pub bank: Box<Account<'info, Bank>>,
+
+    /// Information about the crate.
+    pub crate_token: Box<Account<'info, crate_token::CrateToken>>,
+
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Box<Account<'info, Mint>>,
+
+    /// The borrower, who repays the flash mint.
+    pub borrower: Signer<'info>,
+
+    /// Destination of the flash minted $CASH.
+    #[account(mut)]
+    pub mint_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Source of the repaid $CASH, owned by the borrower.
+    #[account(mut)]
+    pub repay_source: Box<Account<'info, TokenAccount>>,
+
+    /// The issue authority of the [Bank]. See [find_issue_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub issue_authority: UncheckedAccount<'info>,
+
+    /// Program invoked with the flash minted $CASH.
+    /// CHECK: must be executable and not [brrr].
+    pub receiver_program: UncheckedAccount<'info>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+
+    /// [crate_token::program::CrateToken] program.
+    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
+}
+
+/// Accounts for printing $CASH with raw Saber LP tokens.
+#[derive(Accounts)]
+pub struct PrintCashFromLp<'info> {
+    /// Accounts for printing $CASH.
+    /// The `depositor_source` receives the staked Arrow tokens before they are deposited.
+    pub print_cash: PrintCash<'info>,
+
+    /// The Saber LP tokens of the depositor.
+    #[account(mut)]
+    pub depositor_lp_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Arrow staking accounts.
+    pub arrow_stake: ArrowStakeAccounts<'info>,
+}
+
+/// Accounts for burning $CASH for raw Saber LP tokens.
+#[derive(Accounts)]
+pub struct BurnCashToLp<'info> {
+    /// Accounts for burning $CASH.
+    /// The `withdraw_destination` receives the Arrow tokens before they are unstaked.
+    pub burn_cash: BurnCash<'info>,
+
+    /// Destination of the unstaked Saber LP tokens.
+    #[account(mut)]
+    pub lp_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Sunny pool fee account for the Saber LP tokens.
+    #[account(mut)]
+    pub sunny_pool_fee_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Arrow staking accounts.
+    pub arrow_stake: ArrowStakeAccounts<'info>,
+}
+
+/// Accounts for printing $CASH with the underlying tokens of a Saber pool.
+#[derive(Accounts)]
+pub struct PrintCashFromUnderlying<'info> {
+    /// Accounts for printing $CASH with Saber LP tokens.
+    /// The `depositor_lp_tokens` receive the LP tokens before they are staked.
+    pub print_cash_from_lp: PrintCashFromLp<'info>,
+
+    /// Saber liquidity accounts.
+    pub saber: SaberLiquidityAccounts<'info>,
+
+    /// Token A of the depositor.
+    #[account(mut)]
+    pub depositor_token_a: Box<Account<'info, TokenAccount>>,
+
+    /// Token B of the depositor.
+    #[account(mut)]
+    pub depositor_token_b: Box<Account<'info, TokenAccount>>,
+}
+
+/// Accounts for burning $CASH for an underlying token of a Saber pool.
+#[derive(Accounts)]
+pub struct BurnCashToUnderlying<'info> {
+    /// Accounts for burning $CASH for Saber LP tokens.
+    /// The `lp_destination` receives the LP tokens before they are withdrawn.
+    pub burn_cash_to_lp: BurnCashToLp<'info>,
+
+    /// Saber liquidity accounts.
+    pub saber: SaberLiquidityAccounts<'info>,
+
+    /// Destination of the withdrawn underlying tokens.
+    #[account(mut)]
+    pub underlying_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Saber admin fee account of the withdrawn token.
+    /// CHECK: checked against the [SwapInfo].
+    #[account(mut)]
+    pub admin_fees: UncheckedAccount<'info>,
+}
+
+/// Accounts common to printing and burning $CASH with a [bankman::CollateralKind::YieldBearing].
+#[derive(Accounts)]
+pub struct BrrrYieldBearingCommon<'info> {
+    /// Information about the bank.
+    pub bank: Box<Account<'info, Bank>>,
+
+    /// The [Collateral].
+    pub collateral: Box<Account<'info, Collateral>>,
+
+    /// Information about the crate.
+    pub crate_token: Box<Account<'info, crate_token::CrateToken>>,
+
+    /// [Mint] of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_mint: Box<Account<'info, Mint>>,
+
+    /// [TokenAccount] holding the [Collateral] tokens of the [crate_token::CrateToken].
+    #[account(mut)]
+    pub crate_collateral_tokens: Box<Account<'info, TokenAccount>>,
+
+    /// Yield-bearing token accounts.
+    pub yield_bearing: YieldBearingAccounts<'info>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+
+    /// [crate_token::program::CrateToken] program.
+    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
+}
+
+/// Accounts for printing $CASH with a yield-bearing token.
+#[derive(Accounts)]
+pub struct PrintCashYieldBearing<'info> {
+    /// Common accounts.
+    pub common: BrrrYieldBearingCommon<'info>,
+
+    /// The depositor into the pool.
+    /// This may be the owner or an approved delegate of the `depositor_source`.
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// The source of the deposited [Collateral] tokens.
+    #[account(mut)]
+    pub depositor_source: Box<Account<'info, TokenAccount>>,
+
+    /// Destination of the issued $CASH.
+    #[account(mut)]
+    pub mint_destination: Box<Account<'info, TokenAccount>>,
+
+    /// The issue authority of the [Bank]. See [find_issue_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub issue_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for burning $CASH for a yield-bearing token.
+#[derive(Accounts)]
+pub struct BurnCashYieldBearing<'info> {
+    /// Common accounts.
+    pub common: BrrrYieldBearingCommon<'info>,
+
+    /// The burner of the $CASH.
+    /// This may be the owner or an approved delegate of the `burned_cash_source`.
+    #[account(mut)]
+    pub burner: Signer<'info>,
+
+    /// The source of the burned $CASH.
+    #[account(mut)]
+    pub burned_cash_source: Box<Account<'info, TokenAccount>>,
+
+    /// Destination of the issued tokens.
+    #[account(mut)]
+    pub withdraw_destination: Box<Account<'info, TokenAccount>>,
+
+    /// Author fee token destination
+    #[account(mut)]
+    pub author_fee_destination: Account<'info, TokenAccount>,
+
+    /// Protocol fee token destination
+    #[account(mut)]
+    pub protocol_fee_destination: Account<'info, TokenAccount>,
+
+    /// The withdraw authority of the [Bank]. See [find_withdraw_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub withdraw_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for [brrr::new_redemption_queue].
+#[derive(Accounts)]
+pub struct NewRedemptionQueue<'info> {
+    /// The [Bank].
+    pub bank: Box<Account<'info, Bank>>,
+
+    /// The [Collateral] redeemed by the queue.
+    pub collateral: Box<Account<'info, Collateral>>,
+
+    /// The [RedemptionQueue] to create.
+    #[account(
+        init,
+        seeds = [
+            b"RedemptionQueue".as_ref(),
+            collateral.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + RedemptionQueue::BYTES,
+        payer = payer
+    )]
+    pub queue: Box<Account<'info, RedemptionQueue>>,
+
+    /// $CASH token account, owned by the queue, which escrows redemptions.
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Payer of the queue initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [brrr::enqueue_redemption].
+#[derive(Accounts)]
+pub struct EnqueueRedemption<'info> {
+    /// The [RedemptionQueue].
+    #[account(mut)]
+    pub queue: Box<Account<'info, RedemptionQueue>>,
+
+    /// The [RedemptionTicket] to create at the back of the queue.
+    #[account(
+        init,
+        seeds = [
+            b"RedemptionTicket".as_ref(),
+            queue.key().to_bytes().as_ref(),
+            queue.tail.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + RedemptionTicket::BYTES,
+        payer = owner
+    )]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+
+    /// Owner of the ticket.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The source of the escrowed $CASH.
+    #[account(mut)]
+    pub cash_source: Box<Account<'info, TokenAccount>>,
+
+    /// The [RedemptionQueue::escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    /// The [Collateral] redeemed by the queue.
+    pub collateral: Box<Account<'info, Collateral>>,
+
+    /// Collateral token account which receives the redeemed collateral.
+    pub withdraw_destination: Box<Account<'info, TokenAccount>>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [brrr::cancel_redemption].
+#[derive(Accounts)]
+pub struct CancelRedemption<'info> {
+    /// The [RedemptionQueue].
+    pub queue: Box<Account<'info, RedemptionQueue>>,
+
+    /// The [RedemptionTicket] to cancel.
+    #[account(mut)]
+    pub ticket: Box<Account<'info, RedemptionTicket>>,
+
+    /// The [RedemptionTicket::owner], who receives the rent of a skipped ticket.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The [RedemptionQueue::escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Destination of the unredeemed $CASH.
+    #[account(mut)]
+    pub cash_destination: Box<Account<'info, TokenAccount>>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts of a [RedemptionQueue] being filled.
+#[derive(Accounts)]
+pub struct RedemptionQueueAccounts<'info> {
+    /// The [RedemptionQueue].
+    #[account(mut)]
+    pub queue: Box<Account<'info, RedemptionQueue>>,
+
+    /// The [RedemptionQueue::escrow].
+    #[account(mut)]
+    pub escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Author fee token destination
+    #[account(mut)]
+    pub author_fee_destination: Account<'info, TokenAccount>,
+
+    /// Protocol fee token destination
+    #[account(mut)]
+    pub protocol_fee_destination: Account<'info, TokenAccount>,
+
+    /// The withdraw authority of the [Bank]. See [find_withdraw_authority_address].
+    /// CHECK: this is handled by Vipers.
+    pub withdraw_authority: UncheckedAccount<'info>,
+}
+
+/// Accounts for [brrr::fill_redemptions_saber].
+#[derive(Accounts)]
+pub struct FillRedemptionsSaber<'info> {
+    /// Common accounts.
+    pub common: BrrrCommon<'info>,
+    /// Redemption queue accounts.
+    pub redemptions: RedemptionQueueAccounts<'info>,
+}
+
+/// Accounts for [brrr::fill_redemptions_stablecoin].
+#[derive(Accounts)]
+pub struct FillRedemptionsStablecoin<'info> {
+    /// Common accounts.
+    pub common: BrrrStablecoinCommon<'info>,
+    /// Redemption queue accounts.
+    pub redemptions: RedemptionQueueAccounts<'info>,
+}
+
+/// Accounts for [brrr::fill_redemptions_yield_bearing].
+#[derive(Accounts)]
+pub struct FillRedemptionsYieldBearing<'info> {
+    /// Common accounts.
+    pub common: BrrrYieldBearingCommon<'info>,
+    /// Redemption queue accounts.
+    pub redemptions: RedemptionQueueAccounts<'info>,
+}
+
 /// Errors.
 #[error_code]
 pub enum ErrorCode {
@@ -158,4 +1005,34 @@ pub enum ErrorCode {
     CollateralHardCapHit,
     #[msg("Insufficient pool funds.")]
     InsufficientFunds,
+    #[msg("Collateral kind does not match the instruction.")]
+    CollateralKindMismatch,
+    #[msg("Invalid base pool accounts for metapool.")]
+    InvalidBasePools,
+    #[msg("Less $CASH was printed than the minimum requested.")]
+    PrintSlippageExceeded,
+    #[msg("Token is not in the Saber pool.")]
+    TokenNotInPool,
+    #[msg("Flash mint amount exceeds the ceiling of the bank.")]
+    FlashMintCeilingExceeded,
+    #[msg("Flash mint receiver must be an executable program other than brrr.")]
+    FlashMintInvalidReceiver,
+    #[msg("Redemption amount must be greater than zero.")]
+    RedemptionAmountZero,
+    #[msg("Redemption ticket has no $CASH left to redeem.")]
+    RedemptionTicketEmpty,
+    #[msg("Redemption tickets must be passed in groups, starting from the head of the queue.")]
+    InvalidRedemptionTickets,
+    #[msg("Must be the owner or approved delegate of the source token account.")]
+    UnauthorizedSourceAuthority,
+    #[msg("Amount exceeds the approved delegated amount of the source token account.")]
+    DelegatedAmountExceeded,
+    #[msg("Collateral account groups do not match the amounts.")]
+    InvalidCollateralGroups,
+    #[msg("Collateral withdrawn is worth less than the minimum requested.")]
+    WithdrawSlippageExceeded,
+    #[msg("The bank is paused.")]
+    BankPaused,
+    #[msg("Authority is neither the legacy authority nor the authority of the bank.")]
+    InvalidBankAuthority,
 }
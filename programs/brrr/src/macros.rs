@@ -0,0 +1,11 @@
+/// Generates the signer seeds for a [crate::RedemptionQueue].
+#[macro_export]
+macro_rules! gen_redemption_queue_signer_seeds {
+    ($queue:expr) => {
+        &[
+            b"RedemptionQueue".as_ref(),
+            $queue.collateral.as_ref(),
+            &[$queue.bump],
+        ]
+    };
+}
@@ -0,0 +1,201 @@
+//! Saber metapool adapters
+//!
+//! A metapool is a Saber pool which holds the LP tokens of another ("base") Saber pool
+//! as one of its reserves. Base pools are passed as remaining accounts and are valued
+//! recursively, so any number of reserves may back the collateral.
+//!
+//! Each base pool is a variable-length group `[saber_swap, pool_mint, ...reserves]`.
+//! A group ends where the next account owned by the Saber program begins, so a pool
+//! may have any number of reserves up to [MAX_POOL_RESERVES].
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use bankman::{Collateral, CollateralKind};
+use converter::{AmpFactor, CashStableSwap, StableSwapPool, StableSwapReserve};
+use stable_swap_anchor::SwapInfo;
+use vipers::{assert_keys_eq, invariant, validate::Validate};
+
+use crate::{PricingAccounts, SaberSwapAccounts};
+
+/// Maximum number of reserves of a base pool.
+pub const MAX_POOL_RESERVES: usize = 8;
+
+/// Maximum number of base pools which may back a metapool.
+pub const MAX_BASE_POOLS: usize = 4;
+
+/// Accounts of a base pool of a Saber metapool.
+pub(crate) struct BasePoolAccounts<'info> {
+    saber_swap: Account<'info, SwapInfo>,
+    pool_mint: Account<'info, Mint>,
+    reserves: Vec<Account<'info, TokenAccount>>,
+}
+
+impl<'info> BasePoolAccounts<'info> {
+    /// Loads a base pool from the front of the remaining accounts,
+    /// returning the accounts which follow it.
+    fn load<'a>(accounts: &'a [AccountInfo<'info>]) -> Result<(Self, &'a [AccountInfo<'info>])> {
+        let (saber_swap, pool_mint, rest) = match accounts {
+            [saber_swap, pool_mint, rest @ ..] => (saber_swap, pool_mint, rest),
+            _ => return Err(error!(crate::ErrorCode::InvalidBasePools)),
+        };
+        let num_reserves = rest
+            .iter()
+            .position(|account| *account.owner == stable_swap_anchor::ID)
+            .unwrap_or(rest.len());
+        invariant!(
+            (2..=MAX_POOL_RESERVES).contains(&num_reserves),
+            InvalidBasePools
+        );
+        let (reserves, rest) = rest.split_at(num_reserves);
+        Ok((
+            Self {
+                saber_swap: Account::try_from(saber_swap)?,
+                pool_mint: Account::try_from(pool_mint)?,
+                reserves: reserves
+                    .iter()
+                    .map(Account::try_from)
+                    .collect::<Result<Vec<_>>>()?,
+            },
+            rest,
+        ))
+    }
+
+    fn reserve_refs(&self) -> Vec<&TokenAccount> {
+        self.reserves.iter().map(|reserve| &**reserve).collect()
+    }
+}
+
+/// [SaberSwapAccounts] of a metapool, along with the base pools backing its reserves.
+pub(crate) struct SaberMetapoolAccounts<'a, 'info> {
+    swap: &'a SaberSwapAccounts<'info>,
+    base_pools: Vec<BasePoolAccounts<'info>>,
+}
+
+impl<'a, 'info> SaberMetapoolAccounts<'a, 'info> {
+    /// Loads the base pools of the metapool from the remaining accounts.
+    pub(crate) fn load(
+        swap: &'a SaberSwapAccounts<'info>,
+        remaining_accounts: &[AccountInfo<'info>],
+    ) -> Result<Self> {
+        let mut base_pools = vec![];
+        let mut rest = remaining_accounts;
+        while !rest.is_empty() {
+            invariant!(base_pools.len() < MAX_BASE_POOLS, InvalidBasePools);
+            let (base, next) = BasePoolAccounts::load(rest)?;
+            base_pools.push(base);
+            rest = next;
+        }
+        Ok(Self { swap, base_pools })
+    }
+
+    /// Builds the [StableSwapPool] of a Saber pool, valuing reserves held in base LP tokens recursively.
+    fn build_pool(
+        &self,
+        saber_swap: &SwapInfo,
+        pool_mint: &Mint,
+        reserves: &[&TokenAccount],
+        depth: usize,
+        current_ts: i64,
+    ) -> Result<StableSwapPool> {
+        invariant!(depth <= MAX_BASE_POOLS, InvalidBasePools);
+        let reserves = reserves
+            .iter()
+            .map(|reserve| {
+                Ok(match self.find_base_pool(reserve.mint) {
+                    Some(base) => StableSwapReserve::Lp {
+                        amount: reserve.amount,
+                        pool: Box::new(self.build_pool(
+                            &base.saber_swap,
+                            &base.pool_mint,
+                            &base.reserve_refs(),
+                            depth + 1,
+                            current_ts,
+                        )?),
+                    },
+                    None => StableSwapReserve::Token {
+                        amount: reserve.amount,
+                    },
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(StableSwapPool {
+            amp_factor: AmpFactor {
+                initial_amp_factor: saber_swap.initial_amp_factor,
+                target_amp_factor: saber_swap.target_amp_factor,
+                current_ts,
+                start_ramp_ts: saber_swap.start_ramp_ts,
+                stop_ramp_ts: saber_swap.stop_ramp_ts,
+            },
+            lp_mint_supply: pool_mint.supply,
+            reserves,
+        })
+    }
+
+    fn find_base_pool(&self, mint: Pubkey) -> Option<&BasePoolAccounts<'info>> {
+        self.base_pools
+            .iter()
+            .find(|base| base.pool_mint.key() == mint)
+    }
+
+    /// Mints of every reserve of the metapool and its base pools.
+    fn reserve_mints(&self) -> Vec<Pubkey> {
+        [self.swap.reserve_a.mint, self.swap.reserve_b.mint]
+            .into_iter()
+            .chain(
+                self.base_pools
+                    .iter()
+                    .flat_map(|base| base.reserves.iter().map(|reserve| reserve.mint)),
+            )
+            .collect()
+    }
+}
+
+impl<'a, 'info> PricingAccounts<'info> for SaberMetapoolAccounts<'a, 'info> {
+    type Pricer = CashStableSwap;
+
+    const KIND: CollateralKind = CollateralKind::SaberLpArrow;
+
+    fn validate_collateral(&self, collateral: &Collateral) -> Result<()> {
+        self.swap.validate_collateral(collateral)
+    }
+
+    fn pricer(&self) -> Result<CashStableSwap> {
+        let swap = self.swap;
+        Ok(CashStableSwap {
+            lp_mint_decimals: swap.pool_mint.decimals,
+            pool: self.build_pool(
+                &swap.saber_swap,
+                &swap.pool_mint,
+                &[&swap.reserve_a, &swap.reserve_b],
+                0,
+                Clock::get()?.unix_timestamp,
+            )?,
+        })
+    }
+}
+
+impl<'a, 'info> Validate<'info> for SaberMetapoolAccounts<'a, 'info> {
+    fn validate(&self) -> Result<()> {
+        self.swap.validate()?;
+        let reserve_mints = self.reserve_mints();
+        for (i, base) in self.base_pools.iter().enumerate() {
+            assert_keys_eq!(base.saber_swap.pool_mint, base.pool_mint);
+            // a Saber swap records exactly two reserves
+            invariant!(base.reserves.len() == 2, InvalidBasePools);
+            assert_keys_eq!(base.saber_swap.token_a.reserves, base.reserves[0]);
+            assert_keys_eq!(base.saber_swap.token_b.reserves, base.reserves[1]);
+            invariant!(
+                self.base_pools[..i]
+                    .iter()
+                    .all(|other| other.pool_mint.key() != base.pool_mint.key()),
+                InvalidBasePools
+            );
+            // every base pool must back a reserve
+            invariant!(
+                reserve_mints.contains(&base.pool_mint.key()),
+                InvalidBasePools
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//! Collateral pricing
+
+use anchor_lang::prelude::*;
+use bankman::{Collateral, CollateralKind};
+use converter::CollateralPricer;
+use vipers::validate::Validate;
+
+/// Accounts used to price a [bankman::Collateral] of a specific [CollateralKind].
+///
+/// Supporting a new kind of collateral only requires implementing this trait;
+/// printing and burning are shared across all kinds.
+pub trait PricingAccounts<'info>: Validate<'info> {
+    /// The [CollateralPricer] built from these accounts.
+    type Pricer: CollateralPricer;
+
+    /// The [CollateralKind] these accounts can price.
+    const KIND: CollateralKind;
+
+    /// Checks that these accounts price the given [Collateral].
+    fn validate_collateral(&self, collateral: &Collateral) -> Result<()>;
+
+    /// Builds the [CollateralPricer] from the current state of the accounts.
+    fn pricer(&self) -> Result<Self::Pricer>;
+}
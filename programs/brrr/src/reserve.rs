@@ -0,0 +1,34 @@
+//! Lending reserve adapters
+//!
+//! The reserve layout is parsed by [bankman], which also values yield-bearing collateral
+//! when it is written down.
+
+use anchor_lang::prelude::*;
+use bankman::{Collateral, CollateralKind};
+pub use bankman::{ReserveState, RESERVE_LEN, RESERVE_VERSION};
+use converter::YieldBearingToken;
+use vipers::{assert_keys_eq, validate::Validate};
+
+use crate::{PricingAccounts, YieldBearingAccounts};
+
+impl<'info> PricingAccounts<'info> for YieldBearingAccounts<'info> {
+    type Pricer = YieldBearingToken;
+
+    const KIND: CollateralKind = CollateralKind::YieldBearing;
+
+    fn validate_collateral(&self, collateral: &Collateral) -> Result<()> {
+        assert_keys_eq!(collateral.mint, self.mint);
+        ReserveState::load(collateral, &self.reserve)?;
+        Ok(())
+    }
+
+    fn pricer(&self) -> Result<YieldBearingToken> {
+        ReserveState::unpack(&self.reserve.try_borrow_data()?)?.pricer()
+    }
+}
+
+impl<'info> Validate<'info> for YieldBearingAccounts<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
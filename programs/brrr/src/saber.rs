@@ -3,10 +3,12 @@
 use std::convert::TryFrom;
 
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use bankman::{Collateral, CollateralKind};
 use converter::{CashSwap, SaberSwap};
 use vipers::{assert_keys_eq, validate::Validate};
 
-use crate::SaberSwapAccounts;
+use crate::{PricingAccounts, SaberLiquidityAccounts, SaberSwapAccounts};
 
 impl<'info> TryFrom<&SaberSwapAccounts<'info>> for CashSwap {
     type Error = anchor_lang::error::Error;
@@ -29,6 +31,21 @@ impl<'info> TryFrom<&SaberSwapAccounts<'info>> for CashSwap {
     }
 }
 
+impl<'info> PricingAccounts<'info> for SaberSwapAccounts<'info> {
+    type Pricer = CashSwap;
+
+    const KIND: CollateralKind = CollateralKind::SaberLpArrow;
+
+    fn validate_collateral(&self, collateral: &Collateral) -> Result<()> {
+        assert_keys_eq!(collateral.mint, self.arrow.mint);
+        Ok(())
+    }
+
+    fn pricer(&self) -> Result<CashSwap> {
+        self.try_into()
+    }
+}
+
 impl<'info> Validate<'info> for SaberSwapAccounts<'info> {
     fn validate(&self) -> Result<()> {
         assert_keys_eq!(self.arrow.vendor_miner.mint, self.pool_mint);
@@ -38,3 +55,122 @@ impl<'info> Validate<'info> for SaberSwapAccounts<'info> {
         Ok(())
     }
 }
+
+impl<'info> SaberSwapAccounts<'info> {
+    /// Reloads the pool state after liquidity has been added or removed.
+    pub(crate) fn reload(&mut self) -> Result<()> {
+        self.pool_mint.reload()?;
+        self.reserve_a.reload()?;
+        self.reserve_b.reload()
+    }
+}
+
+impl<'info> SaberLiquidityAccounts<'info> {
+    fn user_context(
+        &self,
+        swap: &SaberSwapAccounts<'info>,
+        user_authority: &Signer<'info>,
+        token_program: &Program<'info, Token>,
+    ) -> stable_swap_anchor::SwapUserContext<'info> {
+        stable_swap_anchor::SwapUserContext {
+            token_program: token_program.to_account_info(),
+            swap_authority: self.swap_authority.to_account_info(),
+            user_authority: user_authority.to_account_info(),
+            swap: swap.saber_swap.to_account_info(),
+        }
+    }
+
+    /// Adds liquidity to the Saber pool, sending the LP tokens to `output_lp`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn deposit(
+        &self,
+        swap: &SaberSwapAccounts<'info>,
+        depositor: &Signer<'info>,
+        depositor_token_a: &Account<'info, TokenAccount>,
+        depositor_token_b: &Account<'info, TokenAccount>,
+        output_lp: &Account<'info, TokenAccount>,
+        token_program: &Program<'info, Token>,
+        token_a_amount: u64,
+        token_b_amount: u64,
+    ) -> Result<()> {
+        stable_swap_anchor::deposit(
+            CpiContext::new(
+                self.saber_program.to_account_info(),
+                stable_swap_anchor::Deposit {
+                    user: self.user_context(swap, depositor, token_program),
+                    input_a: stable_swap_anchor::SwapToken {
+                        user: depositor_token_a.to_account_info(),
+                        reserve: self.reserve_a.to_account_info(),
+                    },
+                    input_b: stable_swap_anchor::SwapToken {
+                        user: depositor_token_b.to_account_info(),
+                        reserve: self.reserve_b.to_account_info(),
+                    },
+                    pool_mint: self.pool_mint.to_account_info(),
+                    output_lp: output_lp.to_account_info(),
+                },
+            ),
+            token_a_amount,
+            token_b_amount,
+            // slippage is checked against the $CASH printed
+            0,
+        )
+    }
+
+    /// Withdraws `pool_token_amount` LP tokens from the Saber pool as the token of `destination`.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn withdraw_one(
+        &self,
+        swap: &SaberSwapAccounts<'info>,
+        burner: &Signer<'info>,
+        input_lp: &Account<'info, TokenAccount>,
+        destination: &Account<'info, TokenAccount>,
+        admin_fees: &UncheckedAccount<'info>,
+        token_program: &Program<'info, Token>,
+        pool_token_amount: u64,
+        minimum_token_amount: u64,
+    ) -> Result<()> {
+        let (reserve, quote_reserves) = if destination.mint == swap.saber_swap.token_a.mint {
+            (&self.reserve_a, &self.reserve_b)
+        } else {
+            (&self.reserve_b, &self.reserve_a)
+        };
+        stable_swap_anchor::withdraw_one(
+            CpiContext::new(
+                self.saber_program.to_account_info(),
+                stable_swap_anchor::WithdrawOne {
+                    user: self.user_context(swap, burner, token_program),
+                    pool_mint: self.pool_mint.to_account_info(),
+                    input_lp: input_lp.to_account_info(),
+                    quote_reserves: quote_reserves.to_account_info(),
+                    output: stable_swap_anchor::SwapOutput {
+                        user_token: stable_swap_anchor::SwapToken {
+                            user: destination.to_account_info(),
+                            reserve: reserve.to_account_info(),
+                        },
+                        fees: admin_fees.to_account_info(),
+                    },
+                },
+            ),
+            pool_token_amount,
+            minimum_token_amount,
+        )
+    }
+
+    /// Validates these accounts against the [SaberSwapAccounts] of the collateral.
+    pub(crate) fn validate_swap(&self, swap: &SaberSwapAccounts<'info>) -> Result<()> {
+        assert_keys_eq!(self.pool_mint.key(), swap.pool_mint.key());
+        assert_keys_eq!(self.reserve_a.key(), swap.reserve_a.key());
+        assert_keys_eq!(self.reserve_b.key(), swap.reserve_b.key());
+        // the swap authority is validated by Saber
+        Ok(())
+    }
+
+    /// Finds the admin fee account of the token of the given mint.
+    pub(crate) fn admin_fees_of(swap: &SaberSwapAccounts<'info>, mint: Pubkey) -> Option<Pubkey> {
+        [&swap.saber_swap.token_a, &swap.saber_swap.token_b]
+            .into_iter()
+            .find(|token| token.mint == mint)
+            .map(|token| token.admin_fees)
+    }
+}
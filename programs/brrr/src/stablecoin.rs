@@ -0,0 +1,31 @@
+//! Stablecoin adapters
+
+use anchor_lang::prelude::*;
+use bankman::{Collateral, CollateralKind};
+use converter::CashStablecoin;
+use vipers::{assert_keys_eq, validate::Validate};
+
+use crate::{PricingAccounts, StablecoinAccounts};
+
+impl<'info> PricingAccounts<'info> for StablecoinAccounts<'info> {
+    type Pricer = CashStablecoin;
+
+    const KIND: CollateralKind = CollateralKind::Stablecoin;
+
+    fn validate_collateral(&self, collateral: &Collateral) -> Result<()> {
+        assert_keys_eq!(collateral.mint, self.mint);
+        Ok(())
+    }
+
+    fn pricer(&self) -> Result<CashStablecoin> {
+        Ok(CashStablecoin {
+            mint_decimals: self.mint.decimals,
+        })
+    }
+}
+
+impl<'info> Validate<'info> for StablecoinAccounts<'info> {
+    fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+//! Struct definitions for accounts that hold state.
+
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+/// A FIFO queue of $CASH redemptions for a [bankman::Collateral].
+///
+/// $CASH is escrowed in the queue until enough collateral is available to redeem it.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct RedemptionQueue {
+    /// The [bankman::Collateral] redeemed.
+    pub collateral: Pubkey,
+    /// Bump.
+    pub bump: u8,
+    /// $CASH token account, owned by this queue, holding the escrowed $CASH.
+    pub escrow: Pubkey,
+    /// Index of the next [RedemptionTicket] to be filled.
+    pub head: u64,
+    /// Index of the next [RedemptionTicket] to be enqueued.
+    pub tail: u64,
+}
+
+impl RedemptionQueue {
+    pub const BYTES: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES + 8 + 8;
+}
+
+/// A request to redeem $CASH for collateral in a [RedemptionQueue].
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct RedemptionTicket {
+    /// The [RedemptionQueue].
+    pub queue: Pubkey,
+    /// Position of the ticket in the queue.
+    pub index: u64,
+    /// Bump.
+    pub bump: u8,
+    /// Owner of the ticket, who may cancel it and receives its rent once it is closed.
+    pub owner: Pubkey,
+    /// Collateral token account which receives the redeemed collateral.
+    pub withdraw_destination: Pubkey,
+    /// Amount of escrowed $CASH which has yet to be redeemed.
+    /// Zero once the ticket has been filled or cancelled.
+    pub cash_amount: u64,
+}
+
+impl RedemptionTicket {
+    pub const BYTES: usize = PUBKEY_BYTES + 8 + 1 + PUBKEY_BYTES * 2 + 8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redemption_queue_len() {
+        assert_eq!(
+            RedemptionQueue::BYTES,
+            RedemptionQueue::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_redemption_ticket_len() {
+        assert_eq!(
+            RedemptionTicket::BYTES,
+            RedemptionTicket::default().try_to_vec().unwrap().len()
+        );
+    }
+}
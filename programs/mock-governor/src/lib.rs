@@ -0,0 +1,283 @@
+//! Stand-in for a [Tribeca](https://tribeca.so) governor, used to test governance of the Bank.
+//!
+//! Each [Governor] controls a smart wallet, a PDA of this program which signs the instructions
+//! of [Proposal]s once they have been approved by the electorate and their timelock has elapsed.
+//! There is no voting: the electorate is a single key, so this program must never be deployed outside of tests.
+//!
+//! It is deployed at the address of the Tribeca govern program on localnet, and its [Governor]
+//! starts with the same fields as a Tribeca governor, so that the Bank accepts it as one.
+#![deny(rustdoc::all)]
+#![allow(rustdoc::missing_doc_code_examples)]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+    pubkey::PUBKEY_BYTES,
+};
+use vipers::prelude::*;
+
+declare_id!("Govz1VyoyLD5BL6CSCxUJLVLsQHRwjfFj1prNsdNg5Jw");
+
+/// Seed of the smart wallet of a [Governor].
+pub const SMART_WALLET_SEED: &[u8] = b"SmartWallet";
+
+/// [mock_governor] program.
+#[program]
+pub mod mock_governor {
+    use super::*;
+
+    /// Creates a [Governor].
+    pub fn create_governor(
+        ctx: Context<CreateGovernor>,
+        electorate: Pubkey,
+        timelock_delay_seconds: i64,
+    ) -> Result<()> {
+        invariant!(timelock_delay_seconds >= 0);
+        let governor_key = ctx.accounts.governor.key();
+        let (smart_wallet, smart_wallet_bump) =
+            Pubkey::find_program_address(&[SMART_WALLET_SEED, governor_key.as_ref()], &crate::ID);
+
+        let governor = &mut ctx.accounts.governor;
+        governor.base = ctx.accounts.base.key();
+        governor.bump = unwrap_bump!(ctx, "governor");
+        governor.proposal_count = 0;
+        governor.electorate = electorate;
+        governor.smart_wallet = smart_wallet;
+        governor.smart_wallet_bump = smart_wallet_bump;
+        governor.timelock_delay_seconds = timelock_delay_seconds;
+        Ok(())
+    }
+
+    /// Creates a [Proposal] to execute the given instructions with the smart wallet as signer.
+    pub fn create_proposal(
+        ctx: Context<CreateProposal>,
+        instructions: Vec<ProposalInstruction>,
+    ) -> Result<()> {
+        let governor = &mut ctx.accounts.governor;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.governor = governor.key();
+        proposal.index = governor.proposal_count;
+        proposal.bump = unwrap_bump!(ctx, "proposal");
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.instructions = instructions;
+        proposal.eta = 0;
+        proposal.executed_at = 0;
+
+        governor.proposal_count = unwrap_int!(governor.proposal_count.checked_add(1));
+        Ok(())
+    }
+
+    /// Approves a [Proposal] on behalf of the electorate, queueing it for execution.
+    pub fn approve_proposal(ctx: Context<ApproveProposal>) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        invariant!(proposal.eta == 0, "proposal already approved");
+        proposal.eta = unwrap_int!(Clock::get()?
+            .unix_timestamp
+            .checked_add(ctx.accounts.governor.timelock_delay_seconds));
+        Ok(())
+    }
+
+    /// Executes an approved [Proposal] whose timelock has elapsed.
+    ///
+    /// The remaining accounts are the accounts of every instruction, followed by their programs.
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let proposal = &mut ctx.accounts.proposal;
+        invariant!(proposal.eta != 0, "proposal not approved");
+        invariant!(now >= proposal.eta, "timelock has not elapsed");
+        invariant!(proposal.executed_at == 0, "proposal already executed");
+        proposal.executed_at = now;
+
+        let governor = &ctx.accounts.governor;
+        let governor_key = governor.key();
+        let seeds: &[&[u8]] = &[
+            SMART_WALLET_SEED,
+            governor_key.as_ref(),
+            &[governor.smart_wallet_bump],
+        ];
+        for ix in proposal.instructions.iter() {
+            invoke_signed(&ix.into(), ctx.remaining_accounts, &[seeds])?;
+        }
+        Ok(())
+    }
+}
+
+/// Governs the smart wallet.
+///
+/// The fields up to [Governor::smart_wallet] match the layout of a Tribeca governor.
+#[account]
+#[derive(Debug, Default)]
+pub struct Governor {
+    /// Base used to derive the [Governor].
+    pub base: Pubkey,
+    /// Bump.
+    pub bump: u8,
+    /// Number of [Proposal]s created.
+    pub proposal_count: u64,
+    /// Account that approves proposals, standing in for a vote.
+    pub electorate: Pubkey,
+    /// The smart wallet, the PDA `["SmartWallet", governor]` of this program.
+    pub smart_wallet: Pubkey,
+    /// Bump of the smart wallet.
+    pub smart_wallet_bump: u8,
+    /// Seconds between the approval and execution of a proposal.
+    pub timelock_delay_seconds: i64,
+}
+
+impl Governor {
+    pub const BYTES: usize = PUBKEY_BYTES + 1 + 8 + PUBKEY_BYTES * 2 + 1 + 8;
+}
+
+/// A set of instructions to be executed by the smart wallet of a [Governor].
+#[account]
+#[derive(Debug, Default)]
+pub struct Proposal {
+    /// The [Governor].
+    pub governor: Pubkey,
+    /// Index of the [Proposal] within the [Governor].
+    pub index: u64,
+    /// Bump.
+    pub bump: u8,
+    /// Creator of the [Proposal].
+    pub proposer: Pubkey,
+    /// Instructions to execute.
+    pub instructions: Vec<ProposalInstruction>,
+    /// Time after which the [Proposal] may be executed. Zero if it has not been approved.
+    pub eta: i64,
+    /// Time at which the [Proposal] was executed. Zero if it has not been executed.
+    pub executed_at: i64,
+}
+
+impl Proposal {
+    /// Number of bytes of a [Proposal] with the given instructions.
+    pub fn space(instructions: &[ProposalInstruction]) -> usize {
+        8 + PUBKEY_BYTES
+            + 8
+            + 1
+            + PUBKEY_BYTES
+            + 4
+            + instructions
+                .iter()
+                .map(|ix| PUBKEY_BYTES + 4 + ix.keys.len() * (PUBKEY_BYTES + 2) + 4 + ix.data.len())
+                .sum::<usize>()
+            + 8
+            + 8
+    }
+}
+
+/// An instruction of a [Proposal].
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProposalInstruction {
+    /// Program to invoke.
+    pub program_id: Pubkey,
+    /// Accounts of the instruction.
+    pub keys: Vec<ProposalAccountMeta>,
+    /// Instruction data.
+    pub data: Vec<u8>,
+}
+
+/// An account of a [ProposalInstruction].
+#[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProposalAccountMeta {
+    /// The account.
+    pub pubkey: Pubkey,
+    /// Whether the account signs.
+    pub is_signer: bool,
+    /// Whether the account is writable.
+    pub is_writable: bool,
+}
+
+impl From<&ProposalInstruction> for Instruction {
+    fn from(ix: &ProposalInstruction) -> Instruction {
+        Instruction {
+            program_id: ix.program_id,
+            accounts: ix
+                .keys
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: ix.data.clone(),
+        }
+    }
+}
+
+/// Accounts for [mock_governor::create_governor].
+#[derive(Accounts)]
+pub struct CreateGovernor<'info> {
+    /// Base used to derive the [Governor].
+    pub base: Signer<'info>,
+    /// The [Governor].
+    #[account(
+        init,
+        seeds = [b"Governor".as_ref(), base.key().as_ref()],
+        bump,
+        space = 8 + Governor::BYTES,
+        payer = payer
+    )]
+    pub governor: Account<'info, Governor>,
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [mock_governor::create_proposal].
+#[derive(Accounts)]
+#[instruction(instructions: Vec<ProposalInstruction>)]
+pub struct CreateProposal<'info> {
+    /// The [Governor].
+    #[account(mut)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(
+        init,
+        seeds = [
+            b"Proposal".as_ref(),
+            governor.key().as_ref(),
+            governor.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        space = Proposal::space(&instructions),
+        payer = payer
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// Creator of the [Proposal].
+    pub proposer: Signer<'info>,
+    /// Payer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// [System] program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [mock_governor::approve_proposal].
+#[derive(Accounts)]
+pub struct ApproveProposal<'info> {
+    /// The [Governor].
+    #[account(has_one = electorate)]
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(mut, has_one = governor)]
+    pub proposal: Account<'info, Proposal>,
+    /// The [Governor::electorate].
+    pub electorate: Signer<'info>,
+}
+
+/// Accounts for [mock_governor::execute_proposal].
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// The [Governor].
+    pub governor: Account<'info, Governor>,
+    /// The [Proposal].
+    #[account(mut, has_one = governor)]
+    pub proposal: Account<'info, Proposal>,
+}
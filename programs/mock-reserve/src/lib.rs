@@ -0,0 +1,147 @@
+//! Mock lending reserve used to test yield-bearing $CASH collateral.
+//!
+//! Reserves are written in the layout read by [bankman::ReserveState]. Anyone may
+//! change a reserve's liquidity, so this program must never be deployed outside of tests.
+#![deny(rustdoc::all)]
+#![allow(rustdoc::missing_doc_code_examples)]
+#![allow(clippy::result_large_err)]
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use bankman::{ReserveState, RESERVE_LEN, RESERVE_VERSION};
+
+declare_id!("5iuUdY88y6XpaHu9gjZenBxJNKkJhHjPjLc3LqCbzUUh");
+
+/// [mock_reserve] program.
+#[program]
+pub mod mock_reserve {
+    use super::*;
+
+    /// Initializes a reserve for the given collateral mint. The reserve starts out stale.
+    pub fn init_reserve(ctx: Context<InitReserve>, liquidity_mint_decimals: u8) -> Result<()> {
+        let state = ReserveState {
+            stale: true,
+            liquidity_mint_decimals,
+            collateral_mint: ctx.accounts.collateral_mint.key(),
+            ..Default::default()
+        };
+        pack(&state, &mut ctx.accounts.reserve.try_borrow_mut_data()?);
+        Ok(())
+    }
+
+    /// Sets the liquidity of the reserve, marking it stale.
+    pub fn set_liquidity(
+        ctx: Context<UpdateReserve>,
+        available_amount: u64,
+        borrowed_amount_wads: u128,
+        collateral_mint_total_supply: u64,
+    ) -> Result<()> {
+        let mut data = ctx.accounts.reserve.try_borrow_mut_data()?;
+        let mut state = ReserveState::unpack(&data)?;
+        state.stale = true;
+        state.available_amount = available_amount;
+        state.borrowed_amount_wads = borrowed_amount_wads;
+        state.collateral_mint_total_supply = collateral_mint_total_supply;
+        pack(&state, &mut data);
+        Ok(())
+    }
+
+    /// Refreshes the reserve in the current slot.
+    pub fn refresh_reserve(ctx: Context<UpdateReserve>) -> Result<()> {
+        let mut data = ctx.accounts.reserve.try_borrow_mut_data()?;
+        let mut state = ReserveState::unpack(&data)?;
+        state.stale = false;
+        state.last_update_slot = Clock::get()?.slot;
+        pack(&state, &mut data);
+        Ok(())
+    }
+}
+
+/// Offsets of the fields of an SPL token-lending `Reserve` which are read by [ReserveState].
+const VERSION_OFFSET: usize = 0;
+const LAST_UPDATE_SLOT_OFFSET: usize = 1;
+const LAST_UPDATE_STALE_OFFSET: usize = 9;
+const LIQUIDITY_MINT_DECIMALS_OFFSET: usize = 74;
+const LIQUIDITY_AVAILABLE_AMOUNT_OFFSET: usize = 171;
+const LIQUIDITY_BORROWED_AMOUNT_WADS_OFFSET: usize = 179;
+const COLLATERAL_MINT_OFFSET: usize = 227;
+const COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET: usize = 259;
+
+/// Writes the reserve state into the data of a reserve account.
+/// All other fields are left untouched.
+fn pack(state: &ReserveState, data: &mut [u8]) {
+    data[VERSION_OFFSET] = RESERVE_VERSION;
+    write(
+        data,
+        LAST_UPDATE_SLOT_OFFSET,
+        &state.last_update_slot.to_le_bytes(),
+    );
+    data[LAST_UPDATE_STALE_OFFSET] = state.stale.into();
+    data[LIQUIDITY_MINT_DECIMALS_OFFSET] = state.liquidity_mint_decimals;
+    write(
+        data,
+        LIQUIDITY_AVAILABLE_AMOUNT_OFFSET,
+        &state.available_amount.to_le_bytes(),
+    );
+    write(
+        data,
+        LIQUIDITY_BORROWED_AMOUNT_WADS_OFFSET,
+        &state.borrowed_amount_wads.to_le_bytes(),
+    );
+    write(data, COLLATERAL_MINT_OFFSET, state.collateral_mint.as_ref());
+    write(
+        data,
+        COLLATERAL_MINT_TOTAL_SUPPLY_OFFSET,
+        &state.collateral_mint_total_supply.to_le_bytes(),
+    );
+}
+
+fn write(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
+
+/// Accounts for [mock_reserve::init_reserve].
+#[derive(Accounts)]
+pub struct InitReserve<'info> {
+    /// The reserve. Must be allocated to [RESERVE_LEN] bytes and owned by this program.
+    /// CHECK: the data must be zeroed.
+    #[account(
+        mut,
+        owner = crate::ID,
+        constraint = reserve.data_len() == RESERVE_LEN,
+        constraint = reserve.try_borrow_data()?.iter().all(|b| *b == 0)
+    )]
+    pub reserve: UncheckedAccount<'info>,
+    /// [Mint] of the collateral token issued by the reserve.
+    pub collateral_mint: Account<'info, Mint>,
+}
+
+/// Accounts for updating a reserve.
+#[derive(Accounts)]
+pub struct UpdateReserve<'info> {
+    /// The reserve.
+    /// CHECK: parsed as a [ReserveState].
+    #[account(mut, owner = crate::ID)]
+    pub reserve: UncheckedAccount<'info>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_is_read_by_bankman() {
+        let state = ReserveState {
+            last_update_slot: 42,
+            stale: false,
+            liquidity_mint_decimals: 6,
+            available_amount: 1_000,
+            borrowed_amount_wads: 20,
+            collateral_mint: Pubkey::new_unique(),
+            collateral_mint_total_supply: 1_000,
+        };
+        let mut data = vec![0u8; RESERVE_LEN];
+        pack(&state, &mut data);
+        assert_eq!(ReserveState::unpack(&data).unwrap(), state);
+    }
+}
@@ -0,0 +1,109 @@
+//! Validate accounts
+
+use crate::*;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use vipers::assert_keys_eq;
+use vipers::validate::Validate;
+
+macro_rules! assert_is_bankman {
+    ($self: ident) => {
+        assert_keys_eq!($self.bankman, $self.bank.bankman, UnauthorizedNotBankman);
+    };
+}
+
+impl<'info> Validate<'info> for NewSavingsVault<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_bankman!(self);
+        require!(
+            self.share_mint.mint_authority == COption::Some(self.vault.key()),
+            NewVaultShareMintAuthority
+        );
+        require!(
+            self.share_mint.freeze_authority.is_none(),
+            NewVaultShareMintAuthority
+        );
+        require!(self.share_mint.supply == 0, NewVaultShareMintSupply);
+        require!(
+            self.share_mint.decimals == bankman::CASH_DECIMALS,
+            NewVaultShareMintDecimals
+        );
+
+        assert_keys_eq!(self.cash_tokens.owner, self.vault);
+        assert_keys_eq!(self.cash_tokens.mint, self.bank.crate_mint);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for SetCooldownSeconds<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_is_bankman!(self);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for Deposit<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.share_mint, self.vault.share_mint);
+        assert_keys_eq!(self.cash_tokens, self.vault.cash_tokens);
+        assert_keys_eq!(self.share_destination.mint, self.share_mint);
+        Ok(())
+    }
+}
+
+impl<'info> Withdraw<'info> {
+    fn validate_accounts(&self) -> Result<()> {
+        assert_keys_eq!(self.share_mint, self.vault.share_mint);
+        assert_keys_eq!(self.cash_tokens, self.vault.cash_tokens);
+        assert_keys_eq!(self.cash_destination.mint, self.cash_tokens.mint);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for Withdraw<'info> {
+    fn validate(&self) -> Result<()> {
+        self.validate_accounts()?;
+        require!(self.vault.cooldown_seconds == 0, CooldownRequired);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for StartCooldown<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(self.vault.cooldown_seconds > 0, CooldownDisabled);
+        assert_keys_eq!(self.share_mint, self.vault.share_mint);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for WithdrawAfterCooldown<'info> {
+    fn validate(&self) -> Result<()> {
+        self.withdraw.validate_accounts()?;
+        assert_keys_eq!(self.cooldown.vault, self.withdraw.vault);
+        assert_keys_eq!(self.cooldown.owner, self.withdraw.owner);
+        assert_keys_eq!(self.owner, self.cooldown.owner);
+        assert_keys_eq!(self.withdraw.share_source, self.cooldown.share_escrow);
+        require!(
+            Clock::get()?.unix_timestamp >= self.cooldown.unlock_ts,
+            CooldownNotElapsed
+        );
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for CancelCooldown<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.vault, self.cooldown.vault);
+        assert_keys_eq!(self.share_escrow, self.cooldown.share_escrow);
+        assert_keys_eq!(self.share_destination.mint, self.vault.share_mint);
+        Ok(())
+    }
+}
+
+impl<'info> Validate<'info> for DepositRevenue<'info> {
+    fn validate(&self) -> Result<()> {
+        assert_keys_eq!(self.share_mint, self.vault.share_mint);
+        assert_keys_eq!(self.cash_tokens, self.vault.cash_tokens);
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+//! Savings events
+#![deny(missing_docs)]
+
+use anchor_lang::prelude::*;
+
+/// Emitted when a [crate::SavingsVault] is created.
+#[event]
+pub struct NewSavingsVaultEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// The [bankman::Bank].
+    #[index]
+    pub bank: Pubkey,
+    /// Mint of the vault's shares.
+    pub share_mint: Pubkey,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when $CASH is staked into a [crate::SavingsVault].
+#[event]
+pub struct DepositEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// The depositor.
+    #[index]
+    pub depositor: Pubkey,
+    /// Amount of $CASH deposited.
+    pub cash_amount: u64,
+    /// Amount of shares minted.
+    pub shares: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when shares of a [crate::SavingsVault] are redeemed for $CASH.
+#[event]
+pub struct WithdrawEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// Owner of the shares.
+    #[index]
+    pub owner: Pubkey,
+    /// Amount of shares burned.
+    pub shares: u64,
+    /// Amount of $CASH withdrawn.
+    pub cash_amount: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when fee revenue is deposited into a [crate::SavingsVault].
+#[event]
+pub struct DepositRevenueEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// Amount of $CASH deposited.
+    pub amount: u64,
+    /// $CASH held by the vault after the deposit.
+    pub cash_balance: u64,
+    /// Shares of the vault outstanding.
+    pub share_supply: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::SavingsVault]'s cooldown is modified.
+#[event]
+pub struct SetCooldownSecondsEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// The new [crate::SavingsVault::cooldown_seconds].
+    pub cooldown_seconds: i64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Cooldown] is started.
+#[event]
+pub struct StartCooldownEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// Owner of the shares.
+    #[index]
+    pub owner: Pubkey,
+    /// Amount of shares to withdraw.
+    pub shares: u64,
+    /// Time at which the shares may be withdrawn.
+    pub unlock_ts: i64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
+
+/// Emitted when a [crate::Cooldown] is cancelled.
+#[event]
+pub struct CancelCooldownEvent {
+    /// The [crate::SavingsVault].
+    #[index]
+    pub vault: Pubkey,
+    /// Owner of the shares.
+    #[index]
+    pub owner: Pubkey,
+    /// Amount of shares returned to the owner.
+    pub shares: u64,
+    /// Timestamp of the event.
+    pub timestamp: i64,
+}
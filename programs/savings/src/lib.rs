@@ -0,0 +1,542 @@
+//! $CASH savings vault accruing protocol fee revenue.
+//!
+//! Savers stake $CASH into a [SavingsVault] and receive shares. As the [bankman::Bank]
+//! deposits fee revenue into the vault, each share becomes redeemable for more $CASH.
+#![deny(rustdoc::all)]
+#![allow(rustdoc::missing_doc_code_examples)]
+#![allow(clippy::result_large_err)]
+
+mod macros;
+
+mod account_validators;
+mod events;
+mod math;
+mod state;
+
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use bankman::Bank;
+use vipers::prelude::*;
+
+pub use events::*;
+pub use math::*;
+pub use state::*;
+
+declare_id!("EfVMqQBXNY5ctXXtkvaSFGXHEmZVxosTJqDNvbkpbXTP");
+
+/// [savings] program.
+#[program]
+pub mod savings {
+    use super::*;
+
+    /// Creates the [SavingsVault] of a [Bank].
+    #[access_control(ctx.accounts.validate())]
+    pub fn new_savings_vault(ctx: Context<NewSavingsVault>) -> Result<()> {
+        let vault = &mut ctx.accounts.vault;
+        vault.bank = ctx.accounts.bank.key();
+        vault.bump = unwrap_bump!(ctx, "vault");
+        vault.share_mint = ctx.accounts.share_mint.key();
+        vault.cash_tokens = ctx.accounts.cash_tokens.key();
+        vault.cooldown_seconds = SavingsVault::DEFAULT_COOLDOWN_SECONDS;
+
+        emit!(NewSavingsVaultEvent {
+            vault: vault.key(),
+            bank: vault.bank,
+            share_mint: vault.share_mint,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Sets the number of seconds savers must wait before withdrawing.
+    ///
+    /// Setting this to zero lets anyone deposit just before fee revenue is distributed
+    /// and withdraw right after it.
+    #[access_control(ctx.accounts.validate())]
+    pub fn set_cooldown_seconds(
+        ctx: Context<SetCooldownSeconds>,
+        cooldown_seconds: i64,
+    ) -> Result<()> {
+        invariant!(cooldown_seconds >= 0, InvalidCooldownSeconds);
+        let vault = &mut ctx.accounts.vault;
+        vault.cooldown_seconds = cooldown_seconds;
+
+        emit!(SetCooldownSecondsEvent {
+            vault: vault.key(),
+            cooldown_seconds,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Stakes $CASH into the [SavingsVault] in exchange for shares.
+    #[access_control(ctx.accounts.validate())]
+    pub fn deposit(ctx: Context<Deposit>, cash_amount: u64) -> Result<()> {
+        let shares = unwrap_int!(shares_for_cash(
+            cash_amount,
+            ctx.accounts.cash_tokens.amount,
+            ctx.accounts.share_mint.supply
+        ));
+        invariant!(shares > 0, ZeroShares);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.cash_source.to_account_info(),
+                    to: ctx.accounts.cash_tokens.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            cash_amount,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[gen_vault_signer_seeds!(ctx.accounts.vault)];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: ctx.accounts.share_mint.to_account_info(),
+                    to: ctx.accounts.share_destination.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
+
+        emit!(DepositEvent {
+            vault: ctx.accounts.vault.key(),
+            depositor: ctx.accounts.depositor.key(),
+            cash_amount,
+            shares,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Redeems shares of the [SavingsVault] for $CASH.
+    ///
+    /// Only allowed if the vault has no cooldown.
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw(ctx: Context<Withdraw>, shares: u64) -> Result<()> {
+        ctx.accounts.withdraw(shares)
+    }
+
+    /// Starts the cooldown of a withdrawal of `shares` shares.
+    ///
+    /// The shares are moved into an escrow owned by the vault until the
+    /// cooldown is withdrawn or cancelled.
+    #[access_control(ctx.accounts.validate())]
+    pub fn start_cooldown(ctx: Context<StartCooldown>, shares: u64) -> Result<()> {
+        invariant!(shares > 0, ZeroShares);
+        let now = Clock::get()?.unix_timestamp;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.share_source.to_account_info(),
+                    to: ctx.accounts.share_escrow.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            shares,
+        )?;
+
+        let cooldown = &mut ctx.accounts.cooldown;
+        cooldown.vault = ctx.accounts.vault.key();
+        cooldown.owner = ctx.accounts.owner.key();
+        cooldown.bump = unwrap_bump!(ctx, "cooldown");
+        cooldown.shares = shares;
+        cooldown.unlock_ts = unwrap_int!(now.checked_add(ctx.accounts.vault.cooldown_seconds));
+        cooldown.share_escrow = ctx.accounts.share_escrow.key();
+
+        emit!(StartCooldownEvent {
+            vault: cooldown.vault,
+            owner: cooldown.owner,
+            shares,
+            unlock_ts: cooldown.unlock_ts,
+            timestamp: now
+        });
+        Ok(())
+    }
+
+    /// Redeems the shares of an elapsed [Cooldown] for $CASH, closing the cooldown
+    /// and its escrow.
+    #[access_control(ctx.accounts.validate())]
+    pub fn withdraw_after_cooldown(ctx: Context<WithdrawAfterCooldown>) -> Result<()> {
+        let withdraw = &ctx.accounts.withdraw;
+        let shares = ctx.accounts.cooldown.shares;
+
+        let signer_seeds: &[&[&[u8]]] = &[gen_vault_signer_seeds!(withdraw.vault)];
+        token::burn(
+            CpiContext::new_with_signer(
+                withdraw.token_program.to_account_info(),
+                token::Burn {
+                    mint: withdraw.share_mint.to_account_info(),
+                    from: withdraw.share_source.to_account_info(),
+                    authority: withdraw.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            withdraw.token_program.to_account_info(),
+            token::CloseAccount {
+                account: withdraw.share_source.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: withdraw.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        withdraw.redeem(shares)
+    }
+
+    /// Cancels a [Cooldown], returning its shares to the owner and closing
+    /// the cooldown and its escrow.
+    #[access_control(ctx.accounts.validate())]
+    pub fn cancel_cooldown(ctx: Context<CancelCooldown>) -> Result<()> {
+        let signer_seeds: &[&[&[u8]]] = &[gen_vault_signer_seeds!(ctx.accounts.vault)];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.share_escrow.to_account_info(),
+                    to: ctx.accounts.share_destination.to_account_info(),
+                    authority: ctx.accounts.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            ctx.accounts.cooldown.shares,
+        )?;
+        token::close_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::CloseAccount {
+                account: ctx.accounts.share_escrow.to_account_info(),
+                destination: ctx.accounts.owner.to_account_info(),
+                authority: ctx.accounts.vault.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        emit!(CancelCooldownEvent {
+            vault: ctx.accounts.cooldown.vault,
+            owner: ctx.accounts.owner.key(),
+            shares: ctx.accounts.cooldown.shares,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+
+    /// Deposits fee revenue into the [SavingsVault] without minting shares,
+    /// raising the value of each share.
+    ///
+    /// Anyone may call this.
+    #[access_control(ctx.accounts.validate())]
+    pub fn deposit_revenue(ctx: Context<DepositRevenue>, amount: u64) -> Result<()> {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.cash_source.to_account_info(),
+                    to: ctx.accounts.cash_tokens.to_account_info(),
+                    authority: ctx.accounts.depositor.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(DepositRevenueEvent {
+            vault: ctx.accounts.vault.key(),
+            amount,
+            cash_balance: unwrap_int!(ctx.accounts.cash_tokens.amount.checked_add(amount)),
+            share_supply: ctx.accounts.share_mint.supply,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+}
+
+impl<'info> Withdraw<'info> {
+    fn withdraw(&self, shares: u64) -> Result<()> {
+        token::burn(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::Burn {
+                    mint: self.share_mint.to_account_info(),
+                    from: self.share_source.to_account_info(),
+                    authority: self.owner.to_account_info(),
+                },
+            ),
+            shares,
+        )?;
+        self.redeem(shares)
+    }
+
+    /// Sends the owner the $CASH backing `shares` burned shares.
+    ///
+    /// Uses the share supply from before the burn.
+    fn redeem(&self, shares: u64) -> Result<()> {
+        let cash_amount = unwrap_int!(cash_for_shares(
+            shares,
+            self.cash_tokens.amount,
+            self.share_mint.supply
+        ));
+
+        let signer_seeds: &[&[&[u8]]] = &[gen_vault_signer_seeds!(self.vault)];
+        token::transfer(
+            CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                token::Transfer {
+                    from: self.cash_tokens.to_account_info(),
+                    to: self.cash_destination.to_account_info(),
+                    authority: self.vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            cash_amount,
+        )?;
+
+        emit!(WithdrawEvent {
+            vault: self.vault.key(),
+            owner: self.owner.key(),
+            shares,
+            cash_amount,
+            timestamp: Clock::get()?.unix_timestamp
+        });
+        Ok(())
+    }
+}
+
+/// Accounts for [savings::new_savings_vault].
+#[derive(Accounts)]
+pub struct NewSavingsVault<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+
+    /// The [SavingsVault] to create.
+    #[account(
+        init,
+        seeds = [
+            b"SavingsVault".as_ref(),
+            bank.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + SavingsVault::BYTES,
+        payer = payer
+    )]
+    pub vault: Account<'info, SavingsVault>,
+
+    /// [Mint] of the vault's shares. Its mint authority must be the vault.
+    pub share_mint: Account<'info, Mint>,
+    /// $CASH tokens of the vault.
+    pub cash_tokens: Account<'info, TokenAccount>,
+
+    /// Payer of the vault initialization.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for [savings::set_cooldown_seconds].
+#[derive(Accounts)]
+pub struct SetCooldownSeconds<'info> {
+    /// The [Bank].
+    pub bank: Account<'info, Bank>,
+    /// The [Bank::bankman].
+    pub bankman: Signer<'info>,
+    /// The [SavingsVault].
+    #[account(mut, has_one = bank)]
+    pub vault: Account<'info, SavingsVault>,
+}
+
+/// Accounts for [savings::deposit].
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    /// The [SavingsVault].
+    pub vault: Account<'info, SavingsVault>,
+    /// [Mint] of the vault's shares.
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+    /// $CASH tokens of the vault.
+    #[account(mut)]
+    pub cash_tokens: Account<'info, TokenAccount>,
+
+    /// The depositor.
+    pub depositor: Signer<'info>,
+    /// Source of the deposited $CASH.
+    #[account(mut)]
+    pub cash_source: Account<'info, TokenAccount>,
+    /// Destination of the minted shares.
+    #[account(mut)]
+    pub share_destination: Account<'info, TokenAccount>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for [savings::withdraw].
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    /// The [SavingsVault].
+    pub vault: Account<'info, SavingsVault>,
+    /// [Mint] of the vault's shares.
+    #[account(mut)]
+    pub share_mint: Account<'info, Mint>,
+    /// $CASH tokens of the vault.
+    #[account(mut)]
+    pub cash_tokens: Account<'info, TokenAccount>,
+
+    /// Owner of the shares.
+    pub owner: Signer<'info>,
+    /// Source of the burned shares.
+    #[account(mut)]
+    pub share_source: Account<'info, TokenAccount>,
+    /// Destination of the withdrawn $CASH.
+    #[account(mut)]
+    pub cash_destination: Account<'info, TokenAccount>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for [savings::start_cooldown].
+#[derive(Accounts)]
+pub struct StartCooldown<'info> {
+    /// The [SavingsVault].
+    pub vault: Account<'info, SavingsVault>,
+    /// The [Cooldown] to create.
+    #[account(
+        init,
+        seeds = [
+            b"Cooldown".as_ref(),
+            vault.key().to_bytes().as_ref(),
+            owner.key().to_bytes().as_ref()
+        ],
+        bump,
+        space = 8 + Cooldown::BYTES,
+        payer = owner
+    )]
+    pub cooldown: Account<'info, Cooldown>,
+    /// Escrow of the shares, owned by the vault.
+    #[account(
+        init,
+        seeds = [
+            b"CooldownShares".as_ref(),
+            cooldown.key().to_bytes().as_ref()
+        ],
+        bump,
+        token::mint = share_mint,
+        token::authority = vault,
+        payer = owner
+    )]
+    pub share_escrow: Account<'info, TokenAccount>,
+    /// [Mint] of the vault's shares.
+    pub share_mint: Account<'info, Mint>,
+    /// Owner of the shares.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    /// Source of the escrowed shares.
+    #[account(mut)]
+    pub share_source: Account<'info, TokenAccount>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+    /// System program.
+    pub system_program: Program<'info, System>,
+    /// Rent sysvar.
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts for [savings::withdraw_after_cooldown].
+#[derive(Accounts)]
+pub struct WithdrawAfterCooldown<'info> {
+    /// Withdraw accounts. The share source must be the [Cooldown::share_escrow].
+    pub withdraw: Withdraw<'info>,
+    /// The [Cooldown], which is closed to the owner.
+    #[account(mut, close = owner)]
+    pub cooldown: Account<'info, Cooldown>,
+    /// Owner of the shares, who receives the rent of the [Cooldown].
+    /// CHECK: checked against the withdraw owner.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+}
+
+/// Accounts for [savings::cancel_cooldown].
+#[derive(Accounts)]
+pub struct CancelCooldown<'info> {
+    /// The [Cooldown], which is closed to the owner.
+    #[account(mut, has_one = owner, close = owner)]
+    pub cooldown: Account<'info, Cooldown>,
+    /// Owner of the shares.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The [SavingsVault].
+    pub vault: Account<'info, SavingsVault>,
+    /// The [Cooldown::share_escrow].
+    #[account(mut)]
+    pub share_escrow: Account<'info, TokenAccount>,
+    /// Destination of the returned shares.
+    #[account(mut)]
+    pub share_destination: Account<'info, TokenAccount>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Accounts for [savings::deposit_revenue].
+#[derive(Accounts)]
+pub struct DepositRevenue<'info> {
+    /// The [SavingsVault].
+    pub vault: Account<'info, SavingsVault>,
+    /// [Mint] of the vault's shares.
+    pub share_mint: Account<'info, Mint>,
+    /// $CASH tokens of the vault.
+    #[account(mut)]
+    pub cash_tokens: Account<'info, TokenAccount>,
+
+    /// The depositor of the revenue.
+    pub depositor: Signer<'info>,
+    /// Source of the deposited $CASH.
+    #[account(mut)]
+    pub cash_source: Account<'info, TokenAccount>,
+
+    /// [Token] program.
+    pub token_program: Program<'info, Token>,
+}
+
+/// Errors.
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Must be the bankman.")]
+    UnauthorizedNotBankman,
+
+    #[msg(
+        "new_savings_vault: share mint must be owned by the vault",
+        offset = 10
+    )]
+    NewVaultShareMintAuthority,
+    #[msg("new_savings_vault: share mint must have zero supply")]
+    NewVaultShareMintSupply,
+    #[msg("new_savings_vault: share mint must have the decimals of $CASH")]
+    NewVaultShareMintDecimals,
+
+    #[msg("deposit: no shares would be minted", offset = 20)]
+    ZeroShares,
+
+    #[msg("cooldown: seconds must not be negative", offset = 30)]
+    InvalidCooldownSeconds,
+    #[msg("cooldown: the vault requires a cooldown before withdrawing")]
+    CooldownRequired,
+    #[msg("cooldown: the vault has no cooldown")]
+    CooldownDisabled,
+    #[msg("cooldown: the cooldown has not elapsed")]
+    CooldownNotElapsed,
+}
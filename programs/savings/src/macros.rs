@@ -0,0 +1,11 @@
+/// Generates the signer seeds for a [crate::SavingsVault].
+#[macro_export]
+macro_rules! gen_vault_signer_seeds {
+    ($vault:expr) => {
+        &[
+            b"SavingsVault".as_ref(),
+            $vault.bank.as_ref(),
+            &[$vault.bump],
+        ]
+    };
+}
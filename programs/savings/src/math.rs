@@ -0,0 +1,93 @@
+//! Share math
+//!
+//! The vault is valued as if it held [VIRTUAL_CASH] more $CASH and [VIRTUAL_SHARES] more
+//! shares than it does. These virtual shares keep a deposit from being rounded away by
+//! a donation into a near-empty vault, and take the revenue deposited while the vault
+//! has no savers, so that the first saver cannot claim it.
+
+/// Amount of $CASH the vault is valued as holding in addition to its balance.
+pub const VIRTUAL_CASH: u64 = 1_000_000;
+
+/// Number of shares the vault is valued as having in addition to its supply.
+pub const VIRTUAL_SHARES: u64 = 1_000_000;
+
+/// Computes the number of shares minted for depositing `cash_amount` $CASH
+/// into a vault holding `cash_balance` $CASH and `share_supply` shares, rounding down.
+///
+/// Shares are minted 1:1 with $CASH into an empty vault.
+pub fn shares_for_cash(cash_amount: u64, cash_balance: u64, share_supply: u64) -> Option<u64> {
+    u64::try_from(
+        u128::from(cash_amount)
+            .checked_mul(u128::from(share_supply).checked_add(VIRTUAL_SHARES.into())?)?
+            .checked_div(u128::from(cash_balance).checked_add(VIRTUAL_CASH.into())?)?,
+    )
+    .ok()
+}
+
+/// Computes the amount of $CASH redeemed for `shares` shares of a vault holding
+/// `cash_balance` $CASH and `share_supply` shares, rounding down.
+pub fn cash_for_shares(shares: u64, cash_balance: u64, share_supply: u64) -> Option<u64> {
+    let cash_amount = u64::try_from(
+        u128::from(shares)
+            .checked_mul(u128::from(cash_balance).checked_add(VIRTUAL_CASH.into())?)?
+            .checked_div(u128::from(share_supply).checked_add(VIRTUAL_SHARES.into())?)?,
+    )
+    .ok()?;
+    Some(cash_amount.min(cash_balance))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_vault() {
+        assert_eq!(shares_for_cash(1_000_000, 0, 0), Some(1_000_000));
+        assert_eq!(cash_for_shares(1_000_000, 0, 0), Some(0));
+    }
+
+    #[test]
+    fn test_revenue_raises_share_value() {
+        // 100 $CASH of deposits, then 10 $CASH of revenue
+        let shares = shares_for_cash(1_000_000, 110_000_000, 100_000_000).unwrap();
+        assert_eq!(shares, 909_909);
+        // the virtual shares take 1% of the revenue
+        assert_eq!(
+            cash_for_shares(100_000_000, 110_000_000, 100_000_000),
+            Some(109_900_990)
+        );
+        // rounding favors the vault
+        assert!(cash_for_shares(shares, 111_000_000, 100_909_909).unwrap() <= 1_000_000);
+    }
+
+    #[test]
+    fn test_revenue_before_first_saver() {
+        // 10 $CASH of revenue is deposited before anyone saves
+        let shares = shares_for_cash(100_000_000, 10_000_000, 0).unwrap();
+        assert_eq!(shares, 9_090_909);
+        // the first saver gets back their deposit, not the revenue
+        let cash_amount = cash_for_shares(shares, 110_000_000, shares).unwrap();
+        assert!(cash_amount <= 100_000_000);
+        assert!(cash_amount >= 100_000_000 - 11);
+    }
+
+    #[test]
+    fn test_donation_cannot_round_away_deposits() {
+        // an attacker deposits the smallest amount, then donates 1,000 $CASH
+        let attacker_shares = shares_for_cash(1, 0, 0).unwrap();
+        assert_eq!(attacker_shares, 1);
+        let cash_balance = 1 + 1_000_000_000;
+
+        // a victim deposit of 100 $CASH still mints shares worth nearly as much
+        let victim_shares = shares_for_cash(100_000_000, cash_balance, attacker_shares).unwrap();
+        assert!(victim_shares > 0);
+        let cash_balance = cash_balance + 100_000_000;
+        let share_supply = attacker_shares + victim_shares;
+        let victim_cash = cash_for_shares(victim_shares, cash_balance, share_supply).unwrap();
+        assert!(victim_cash >= 100_000_000 - 1_001);
+
+        // and the attacker loses nearly all of the donation
+        let attacker_cash = cash_for_shares(attacker_shares, cash_balance, share_supply).unwrap();
+        assert!(attacker_cash < 2_000);
+    }
+}
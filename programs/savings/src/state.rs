@@ -0,0 +1,85 @@
+use anchor_lang::{prelude::*, solana_program::pubkey::PUBKEY_BYTES};
+
+/// Holds the $CASH staked by savers.
+///
+/// Savers receive shares of the vault, which are redeemable for a pro rata portion of
+/// its $CASH. Fee revenue deposited into the vault raises the value of each share.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct SavingsVault {
+    /// The [bankman::Bank].
+    pub bank: Pubkey,
+    /// Bump.
+    pub bump: u8,
+    /// Mint of the vault's shares.
+    pub share_mint: Pubkey,
+    /// $CASH tokens held by the vault.
+    pub cash_tokens: Pubkey,
+    /// Number of seconds a saver must wait between starting a [Cooldown] and withdrawing.
+    /// If zero, savers may withdraw at any time.
+    pub cooldown_seconds: i64,
+}
+
+impl SavingsVault {
+    pub const BYTES: usize = PUBKEY_BYTES + 1 + PUBKEY_BYTES * 2 + 8;
+
+    /// The [SavingsVault::cooldown_seconds] of a new vault: seven days.
+    ///
+    /// Without a cooldown, anyone may deposit just before fee revenue is distributed
+    /// and withdraw right after it, taking a share of the revenue without saving.
+    pub const DEFAULT_COOLDOWN_SECONDS: i64 = 7 * 24 * 60 * 60;
+}
+
+/// A pending withdrawal of shares from a [SavingsVault] with a cooldown.
+#[account]
+#[derive(Copy, Debug, Default, PartialEq, Eq)]
+pub struct Cooldown {
+    /// The [SavingsVault].
+    pub vault: Pubkey,
+    /// Owner of the shares.
+    pub owner: Pubkey,
+    /// Bump.
+    pub bump: u8,
+    /// Number of shares which may be withdrawn once the cooldown has elapsed.
+    pub shares: u64,
+    /// Time at which the shares may be withdrawn.
+    pub unlock_ts: i64,
+    /// Token account owned by the [SavingsVault] holding the shares until the
+    /// cooldown is withdrawn or cancelled.
+    pub share_escrow: Pubkey,
+}
+
+impl Cooldown {
+    pub const BYTES: usize = PUBKEY_BYTES * 2 + 1 + 8 + 8 + PUBKEY_BYTES;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_savings_vault_len() {
+        assert_eq!(
+            SavingsVault::BYTES,
+            SavingsVault::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_cooldown_len() {
+        assert_eq!(
+            Cooldown::BYTES,
+            Cooldown::default().try_to_vec().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn test_bankman_savings_vault_address() {
+        let bank = Pubkey::new_unique();
+        assert_eq!(crate::ID, bankman::addresses::SAVINGS_PROGRAM_ID);
+        assert_eq!(
+            Pubkey::find_program_address(&[b"SavingsVault", bank.as_ref()], &crate::ID),
+            bankman::addresses::find_savings_vault_address(&bank)
+        );
+    }
+}
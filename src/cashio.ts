@@ -25,17 +25,17 @@ import {
   TOKEN_PROGRAM_ID,
   TokenAmount,
 } from "@saberhq/token-utils";
-import type { PublicKey, Signer } from "@solana/web3.js";
-import { Keypair, SystemProgram } from "@solana/web3.js";
+import type { Signer } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 
+import { CASH_DECIMALS, CASHIO_ADDRESSES, generateCollateralAddress } from ".";
 import {
-  BRRR_ISSUE_AUTHORITY,
-  BURN_WITHDRAW_AUTHORITY,
-  CASH_DECIMALS,
-  CASHIO_ADDRESSES,
-  generateCollateralAddress,
-} from ".";
-import { generateBankAddress } from "./pda";
+  generateBankAddress,
+  generateCollateralMetadataAddress,
+  generateIssueAuthorityAddress,
+  generateWithdrawAuthorityAddress,
+  getBankAuthorities,
+} from "./pda";
 import type { BankData, BankmanIDL, BankmanProgram } from "./programs/bankman";
 import { BankmanJSON } from "./programs/bankman";
 import type { BrrrProgram } from "./programs/brrr";
@@ -126,6 +126,8 @@ export class CashioSDK {
   }> {
     const [crateKey, crateBump] = await generateCrateAddress(mintKP.publicKey);
     const [bankKey, aggBump] = await generateBankAddress(crateKey);
+    const [issueAuthority] = await generateIssueAuthorityAddress(bankKey);
+    const [withdrawAuthority] = await generateWithdrawAuthorityAddress(bankKey);
     const initMintTX = await createInitMintInstructions({
       provider: this.provider,
       mintKP,
@@ -140,8 +142,8 @@ export class CashioSDK {
           payer,
           bank: bankKey,
           crateToken: crateKey,
-          brrrIssueAuthority: BRRR_ISSUE_AUTHORITY,
-          burnWithdrawAuthority: BURN_WITHDRAW_AUTHORITY,
+          brrrIssueAuthority: issueAuthority,
+          burnWithdrawAuthority: withdrawAuthority,
           admin,
           systemProgram: SystemProgram.programId,
           crateTokenProgram: CRATE_ADDRESSES.CrateToken,
@@ -158,11 +160,16 @@ export class CashioSDK {
   async authorizeCollateral({
     bankKey,
     mint,
+    kind = { saberLpArrow: {} },
     curator = this.provider.wallet.publicKey,
     payer = this.provider.wallet.publicKey,
   }: {
     bankKey: PublicKey;
     mint: PublicKey;
+    kind?:
+      | { saberLpArrow: Record<string, never> }
+      | { stablecoin: Record<string, never> }
+      | { yieldBearing: Record<string, never> };
     curator?: PublicKey;
     payer?: PublicKey;
   }): Promise<{ tx: TransactionEnvelope; collateralKey: PublicKey }> {
@@ -205,7 +212,7 @@ export class CashioSDK {
       ...(bankATA.instruction ? [bankATA.instruction] : []),
       ...(createATA.instruction ? [createATA.instruction] : []),
       ...(feeATA.instruction ? [feeATA.instruction] : []),
-      this.programs.Bankman.instruction.authorizeCollateral(bump, {
+      this.programs.Bankman.instruction.authorizeCollateral(bump, kind, {
         accounts: {
           bank: bankKey,
           collateral: collateralKey,
@@ -219,6 +226,57 @@ export class CashioSDK {
     return { tx: newStakePoolTX, collateralKey };
   }
 
+  /**
+   * Migrates a Bank in a previous layout to the current layout.
+   * @returns
+   */
+  migrateBank({
+    bankKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    bankKey: PublicKey;
+    payer?: PublicKey;
+  }): TransactionEnvelope {
+    return new TransactionEnvelope(this.provider, [
+      this.programs.Bankman.instruction.migrateBank({
+        accounts: {
+          bank: bankKey,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Migrates a Collateral in a previous layout to the current layout.
+   * @returns
+   */
+  async migrateCollateral({
+    bankKey,
+    mint,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    bankKey: PublicKey;
+    mint: PublicKey;
+    payer?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [collateralKey] = await generateCollateralAddress(
+      bankKey,
+      mint,
+      this.programs.Bankman.programId
+    );
+    return new TransactionEnvelope(this.provider, [
+      this.programs.Bankman.instruction.migrateCollateral({
+        accounts: {
+          collateral: collateralKey,
+          payer,
+          systemProgram: SystemProgram.programId,
+        },
+      }),
+    ]);
+  }
+
   /**
    * Set collateral hard cap
    * @returns
@@ -226,11 +284,11 @@ export class CashioSDK {
   async setCollateralHardCap({
     bankKey,
     hardCap,
-    curator = this.provider.wallet.publicKey,
+    riskManager = this.provider.wallet.publicKey,
   }: {
     bankKey: PublicKey;
     hardCap: TokenAmount;
-    curator?: PublicKey;
+    riskManager?: PublicKey;
   }): Promise<TransactionEnvelope> {
     const [collateralKey] = await generateCollateralAddress(
       bankKey,
@@ -242,10 +300,97 @@ export class CashioSDK {
         accounts: {
           bank: bankKey,
           collateral: collateralKey,
+          riskManager,
+        },
+      }),
+    ]);
+  }
+
+  /**
+   * Creates the empty metadata of a collateral.
+   * @returns
+   */
+  async newCollateralMetadata({
+    bankKey,
+    mint,
+    curator = this.provider.wallet.publicKey,
+    payer = this.provider.wallet.publicKey,
+  }: {
+    bankKey: PublicKey;
+    mint: PublicKey;
+    curator?: PublicKey;
+    payer?: PublicKey;
+  }): Promise<{ tx: TransactionEnvelope; metadataKey: PublicKey }> {
+    const [collateralKey] = await generateCollateralAddress(
+      bankKey,
+      mint,
+      this.programs.Bankman.programId
+    );
+    const [metadataKey] = await generateCollateralMetadataAddress(
+      collateralKey,
+      this.programs.Bankman.programId
+    );
+    const tx = new TransactionEnvelope(this.provider, [
+      this.programs.Bankman.instruction.newCollateralMetadata({
+        accounts: {
+          bank: bankKey,
+          collateral: collateralKey,
+          metadata: metadataKey,
           curator,
+          payer,
+          systemProgram: SystemProgram.programId,
         },
       }),
     ]);
+    return { tx, metadataKey };
+  }
+
+  /**
+   * Sets the metadata of a collateral.
+   * @returns
+   */
+  async setCollateralMetadata({
+    bankKey,
+    mint,
+    name,
+    underlyingMints,
+    saberSwap = PublicKey.default,
+    riskTier,
+    curator = this.provider.wallet.publicKey,
+  }: {
+    bankKey: PublicKey;
+    mint: PublicKey;
+    name: string;
+    underlyingMints: PublicKey[];
+    saberSwap?: PublicKey;
+    riskTier: number;
+    curator?: PublicKey;
+  }): Promise<TransactionEnvelope> {
+    const [collateralKey] = await generateCollateralAddress(
+      bankKey,
+      mint,
+      this.programs.Bankman.programId
+    );
+    const [metadataKey] = await generateCollateralMetadataAddress(
+      collateralKey,
+      this.programs.Bankman.programId
+    );
+    return new TransactionEnvelope(this.provider, [
+      this.programs.Bankman.instruction.setCollateralMetadata(
+        name,
+        underlyingMints,
+        saberSwap,
+        riskTier,
+        {
+          accounts: {
+            bank: bankKey,
+            collateral: collateralKey,
+            metadata: metadataKey,
+            curator,
+          },
+        }
+      ),
+    ]);
   }
 
   /**
@@ -255,12 +400,12 @@ export class CashioSDK {
   async withdrawAuthorFees({
     bankKey,
     amount,
-    bankman = this.provider.wallet.publicKey,
+    feeManager = this.provider.wallet.publicKey,
     recipient = this.provider.wallet.publicKey,
   }: {
     bankKey: PublicKey;
     amount: TokenAmount;
-    bankman?: PublicKey;
+    feeManager?: PublicKey;
     recipient?: PublicKey;
   }): Promise<TransactionEnvelope> {
     const [collateralKey] = await generateCollateralAddress(
@@ -282,7 +427,7 @@ export class CashioSDK {
       this.programs.Bankman.instruction.withdrawAuthorFee(amount.toU64(), {
         accounts: {
           bank: bankKey,
-          bankman,
+          feeManager,
           collateral: collateralKey,
           authorFees,
           destination: destination.address,
@@ -380,7 +525,7 @@ export class CashioSDK {
             swap,
             arrowMint: collateralAmount.token.mintAccount,
           }),
-          issueAuthority: BRRR_ISSUE_AUTHORITY,
+          issueAuthority: (await getBankAuthorities(bankKey)).issueAuthority,
           depositor,
           depositorSource: depositorATAs.accounts.collateral,
           mintDestination: depositorATAs.accounts.cash,
@@ -448,7 +593,8 @@ export class CashioSDK {
             swap,
             arrowMint: arrowMint,
           }),
-          withdrawAuthority: BURN_WITHDRAW_AUTHORITY,
+          withdrawAuthority: (await getBankAuthorities(bankKey))
+            .withdrawAuthority,
           burner,
           burnedCashSource: burnerATAs.accounts.crate,
           withdrawDestination: burnerATAs.accounts.withdraw,
@@ -1,7 +1,12 @@
 import { utils } from "@project-serum/anchor";
 import { PublicKey } from "@solana/web3.js";
 
-import { CASHIO_ADDRESSES } from ".";
+import {
+  BANK_KEY,
+  BRRR_ISSUE_AUTHORITY,
+  BURN_WITHDRAW_AUTHORITY,
+  CASHIO_ADDRESSES,
+} from ".";
 
 /**
  * Generates the canonical Bank PDA.
@@ -38,3 +43,78 @@ export const generateCollateralAddress = (
     programID
   );
 };
+
+/**
+ * Generates the CollateralMetadata PDA of a Collateral.
+ *
+ * @param collateral Collateral.
+ * @param programID
+ * @returns
+ */
+export const generateCollateralMetadataAddress = (
+  collateral: PublicKey,
+  programID: PublicKey = CASHIO_ADDRESSES.Bankman
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("CollateralMetadata"), collateral.toBuffer()],
+    programID
+  );
+};
+
+/**
+ * Generates the issue authority PDA of a Bank.
+ *
+ * @param bank Bank.
+ * @param programID
+ * @returns
+ */
+export const generateIssueAuthorityAddress = (
+  bank: PublicKey,
+  programID: PublicKey = CASHIO_ADDRESSES.Brrr
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("print"), bank.toBuffer()],
+    programID
+  );
+};
+
+/**
+ * Generates the withdraw authority PDA of a Bank.
+ *
+ * @param bank Bank.
+ * @param programID
+ * @returns
+ */
+export const generateWithdrawAuthorityAddress = (
+  bank: PublicKey,
+  programID: PublicKey = CASHIO_ADDRESSES.Brrr
+): Promise<[PublicKey, number]> => {
+  return PublicKey.findProgramAddress(
+    [utils.bytes.utf8.encode("burn"), bank.toBuffer()],
+    programID
+  );
+};
+
+/**
+ * Gets the issue and withdraw authorities of a Bank.
+ * The original $CASH Bank uses the legacy authorities shared by the whole program.
+ *
+ * @param bank Bank.
+ * @returns
+ */
+export const getBankAuthorities = async (
+  bank: PublicKey
+): Promise<{
+  issueAuthority: PublicKey;
+  withdrawAuthority: PublicKey;
+}> => {
+  if (bank.equals(BANK_KEY)) {
+    return {
+      issueAuthority: BRRR_ISSUE_AUTHORITY,
+      withdrawAuthority: BURN_WITHDRAW_AUTHORITY,
+    };
+  }
+  const [issueAuthority] = await generateIssueAuthorityAddress(bank);
+  const [withdrawAuthority] = await generateWithdrawAuthorityAddress(bank);
+  return { issueAuthority, withdrawAuthority };
+};
@@ -1,4 +1,6 @@
-import { BN, EventParser } from "@project-serum/anchor";
+import { CRATE_ADDRESSES, generateCrateAddress } from "@crateprotocol/crate-sdk";
+import type { Program } from "@project-serum/anchor";
+import { BN, EventParser, utils, workspace } from "@project-serum/anchor";
 import { expectTX } from "@saberhq/chai-solana";
 import type { StableSwap } from "@saberhq/stableswap-sdk";
 import { deployNewSwap, SWAP_PROGRAM_ID } from "@saberhq/stableswap-sdk";
@@ -6,6 +8,7 @@ import {
   createInitMintInstructions,
   createMint,
   getATAAddress,
+  getOrCreateATA,
   getTokenAccount,
   SPLToken,
   Token,
@@ -13,12 +16,24 @@ import {
   TokenAmount,
   u64,
 } from "@saberhq/token-utils";
-import type { PublicKey } from "@solana/web3.js";
-import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
 import { expect } from "chai";
 
 import type { AddCollateralEvent, CashioSDK } from "../src";
-import { BANKMAN_CODER } from "../src";
+import {
+  BANKMAN_CODER,
+  BRRR_ISSUE_AUTHORITY,
+  BURN_WITHDRAW_AUTHORITY,
+  CASH_DECIMALS,
+  generateBankAddress,
+  getBankAuthorities,
+} from "../src";
 import { createRewarderAndQuarry } from "./quarryUtils";
 import { createSunnyPool } from "./sunnyUtils";
 import { makeSDK } from "./workspace";
@@ -73,6 +88,132 @@ describe("Cashio", () => {
     });
   });
 
+  it("set collateral metadata", async () => {
+    const collateralKP = Keypair.generate();
+    await expectTX(
+      await createInitMintInstructions({
+        provider: sdk.provider,
+        mintKP: collateralKP,
+        decimals: 6,
+      })
+    ).to.be.fulfilled;
+    const mint = collateralKP.publicKey;
+
+    const { tx: authorizeTX } = await sdk.authorizeCollateral({
+      bankKey: bank,
+      mint,
+      kind: { stablecoin: {} },
+    });
+    await expectTX(authorizeTX, "authorize collateral").to.be.fulfilled;
+
+    const { tx: newTX, metadataKey } = await sdk.newCollateralMetadata({
+      bankKey: bank,
+      mint,
+    });
+    await expectTX(newTX, "new metadata").to.be.fulfilled;
+    await expectTX(
+      await sdk.setCollateralMetadata({
+        bankKey: bank,
+        mint,
+        name: "USDC",
+        underlyingMints: [mint],
+        riskTier: 1,
+      }),
+      "set metadata"
+    ).to.be.fulfilled;
+
+    const metadata = (await (
+      workspace.Bankman as Program
+    ).account.collateralMetadata.fetch(metadataKey)) as {
+      name: string;
+      underlyingMints: PublicKey[];
+      riskTier: number;
+    };
+    expect(metadata.name).to.eq("USDC");
+    expect(metadata.underlyingMints).to.have.length(1);
+    expect(metadata.riskTier).to.eq(1);
+  });
+
+  it("cannot migrate a current Bank or Collateral", async () => {
+    await expectTX(sdk.migrateBank({ bankKey: bank }), "migrate bank").to.be
+      .rejected;
+
+    const collateralKP = Keypair.generate();
+    await expectTX(
+      await createInitMintInstructions({
+        provider: sdk.provider,
+        mintKP: collateralKP,
+        decimals: 6,
+      })
+    ).to.be.fulfilled;
+    const { tx } = await sdk.authorizeCollateral({
+      bankKey: bank,
+      mint: collateralKP.publicKey,
+    });
+    await expectTX(tx, "authorize collateral").to.be.fulfilled;
+    await expectTX(
+      await sdk.migrateCollateral({
+        bankKey: bank,
+        mint: collateralKP.publicKey,
+      }),
+      "migrate collateral"
+    ).to.be.rejected;
+  });
+
+  it("new banks must use their own brrr authorities", async () => {
+    const { provider } = sdk;
+    const bankman = workspace.Bankman as Program;
+    const mintKP = Keypair.generate();
+    const [crateToken, crateBump] = await generateCrateAddress(
+      mintKP.publicKey
+    );
+    const [newBankKey, bankBump] = await generateBankAddress(crateToken);
+    await expectTX(
+      await createInitMintInstructions({
+        provider,
+        mintKP,
+        decimals: CASH_DECIMALS,
+        mintAuthority: crateToken,
+        freezeAuthority: crateToken,
+      }),
+      "create mint"
+    ).to.be.fulfilled;
+
+    const newBank = (issueAuthority: PublicKey, withdrawAuthority: PublicKey) =>
+      bankman.methods
+        .newBank(bankBump, crateBump)
+        .accounts({
+          crateMint: mintKP.publicKey,
+          payer: provider.wallet.publicKey,
+          bank: newBankKey,
+          crateToken,
+          brrrIssueAuthority: issueAuthority,
+          burnWithdrawAuthority: withdrawAuthority,
+          admin: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+          crateTokenProgram: CRATE_ADDRESSES.CrateToken,
+        })
+        .rpc();
+
+    await expect(
+      newBank(BRRR_ISSUE_AUTHORITY, BURN_WITHDRAW_AUTHORITY),
+      "legacy authorities"
+    ).to.be.rejected;
+
+    const { issueAuthority, withdrawAuthority } = await getBankAuthorities(
+      newBankKey
+    );
+    const other = await getBankAuthorities(bank);
+    expect(issueAuthority, "distinct from other banks").not.to.eqAddress(
+      other.issueAuthority
+    );
+    await expect(
+      newBank(other.issueAuthority, other.withdrawAuthority),
+      "authorities of another bank"
+    ).to.be.rejected;
+    await newBank(issueAuthority, withdrawAuthority);
+  });
+
   describe("with saber collateral", () => {
     let swap: StableSwap;
     let lpToken: Token;
@@ -270,4 +411,825 @@ describe("Cashio", () => {
       ).to.bignumber.eq("0");
     });
   });
+
+  describe("with yield-bearing collateral", () => {
+    const bankman = workspace.Bankman as Program;
+    const mockReserve = workspace.MockReserve as Program;
+    let mint: PublicKey;
+    let collateral: PublicKey;
+    let reserve: PublicKey;
+    let crateCollateralTokens: PublicKey;
+    let surplusBuffer: PublicKey;
+
+    beforeEach("authorize collateral with a mock reserve", async () => {
+      const { provider } = sdk;
+      const mintKP = Keypair.generate();
+      mint = mintKP.publicKey;
+      await expectTX(
+        await createInitMintInstructions({
+          provider,
+          mintKP,
+          decimals: 6,
+        }),
+        "create collateral mint"
+      ).to.be.fulfilled;
+
+      const { tx, collateralKey } = await sdk.authorizeCollateral({
+        bankKey: bank,
+        mint,
+        kind: { yieldBearing: {} },
+      });
+      await expectTX(tx, "authorize collateral").to.be.fulfilled;
+      collateral = collateralKey;
+
+      const reserveKP = Keypair.generate();
+      reserve = reserveKP.publicKey;
+      const reserveLen = 571;
+      await expectTX(
+        provider.newTX(
+          [
+            SystemProgram.createAccount({
+              fromPubkey: provider.wallet.publicKey,
+              newAccountPubkey: reserve,
+              space: reserveLen,
+              lamports:
+                await provider.connection.getMinimumBalanceForRentExemption(
+                  reserveLen
+                ),
+              programId: mockReserve.programId,
+            }),
+            await mockReserve.methods
+              .initReserve(6)
+              .accounts({ reserve, collateralMint: mint })
+              .instruction(),
+            await bankman.methods
+              .setCollateralReserve()
+              .accounts({
+                bank,
+                collateral,
+                riskManager: provider.wallet.publicKey,
+                reserve,
+                lendingProgram: mockReserve.programId,
+              })
+              .instruction(),
+          ],
+          [reserveKP]
+        ),
+        "create reserve"
+      ).to.be.fulfilled;
+
+      const { crateToken } = (await bankman.account.bank.fetch(bank)) as {
+        crateToken: PublicKey;
+      };
+      crateCollateralTokens = await getATAAddress({ mint, owner: crateToken });
+      const { address, instruction } = await getOrCreateATA({
+        provider,
+        mint: cashToken.mintAccount,
+        owner: bank,
+      });
+      surplusBuffer = address;
+      if (instruction) {
+        await expectTX(provider.newTX([instruction]), "create surplus buffer")
+          .to.be.fulfilled;
+      }
+    });
+
+    const writeDown = (amount: number, refresh: boolean) => {
+      const builder = bankman.methods
+        .writeDownCollateral(new BN(amount))
+        .accounts({
+          bank,
+          bankman: sdk.provider.wallet.publicKey,
+          collateral,
+          collateralMint: mint,
+          crateCollateralTokens,
+          crateMint: cashToken.mintAccount,
+          surplusBuffer,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts([
+          { pubkey: reserve, isSigner: false, isWritable: false },
+        ]);
+      return refresh
+        ? builder.preInstructions([
+            mockReserve.instruction.refreshReserve({
+              accounts: { reserve },
+            }),
+          ])
+        : builder;
+    };
+
+    it("rejects a reserve not owned by the lending program", async () => {
+      await expect(
+        bankman.methods
+          .setCollateralReserve()
+          .accounts({
+            bank,
+            collateral,
+            riskManager: sdk.provider.wallet.publicKey,
+            reserve,
+            lendingProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc(),
+        "wrong lending program"
+      ).to.be.rejected;
+    });
+
+    it("cannot write down against a stale reserve", async () => {
+      await expect(writeDown(0, false).rpc(), "stale reserve").to.be.rejected;
+      await writeDown(0, true).rpc();
+    });
+
+    it("cannot write down more than the backing", async () => {
+      await expect(writeDown(1, true).rpc(), "no backing").to.be.rejected;
+
+      const { writtenDown } = (await bankman.account.collateral.fetch(
+        collateral
+      )) as { writtenDown: BN };
+      expect(writtenDown, "nothing written down").to.bignumber.eq("0");
+    });
+  });
+
+  describe("author fees", () => {
+    const bankman = workspace.Bankman as Program;
+    let mints: PublicKey[];
+    let collaterals: PublicKey[];
+    let authorFees: PublicKey[];
+    let destinations: PublicKey[];
+
+    beforeEach("accrue author fees", async () => {
+      const { provider } = sdk;
+      const wallet = provider.wallet.publicKey;
+      mints = [];
+      collaterals = [];
+      authorFees = [];
+      destinations = [];
+      for (let i = 0; i < 2; i++) {
+        const mintKP = Keypair.generate();
+        const mint = mintKP.publicKey;
+        await expectTX(
+          await createInitMintInstructions({ provider, mintKP, decimals: 6 }),
+          "create collateral mint"
+        ).to.be.fulfilled;
+        const { tx, collateralKey } = await sdk.authorizeCollateral({
+          bankKey: bank,
+          mint,
+        });
+        await expectTX(tx, "authorize collateral").to.be.fulfilled;
+
+        const fees = await getATAAddress({ mint, owner: bank });
+        const destination = await getOrCreateATA({
+          provider,
+          mint,
+          owner: wallet,
+        });
+        await expectTX(
+          provider.newTX([
+            destination.instruction,
+            SPLToken.createMintToInstruction(
+              TOKEN_PROGRAM_ID,
+              mint,
+              fees,
+              wallet,
+              [],
+              1_000
+            ),
+          ]),
+          "accrue fees"
+        ).to.be.fulfilled;
+
+        mints.push(mint);
+        collaterals.push(collateralKey);
+        authorFees.push(fees);
+        destinations.push(destination.address);
+      }
+    });
+
+    it("withdraws the author fees of several collaterals at once", async () => {
+      await bankman.methods
+        .withdrawAuthorFees(null)
+        .accounts({
+          bank,
+          feeManager: sdk.provider.wallet.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .remainingAccounts(
+          collaterals.flatMap((collateral, i) => [
+            { pubkey: collateral, isSigner: false, isWritable: false },
+            { pubkey: authorFees[i], isSigner: false, isWritable: true },
+            { pubkey: destinations[i], isSigner: false, isWritable: true },
+          ])
+        )
+        .rpc();
+
+      for (let i = 0; i < mints.length; i++) {
+        expect(
+          (await getTokenAccount(sdk.provider, authorFees[i])).amount,
+          "author fees withdrawn"
+        ).to.bignumber.eq("0");
+        expect(
+          (await getTokenAccount(sdk.provider, destinations[i])).amount,
+          "treasury received"
+        ).to.bignumber.eq("1000");
+      }
+    });
+
+    it("rejects destinations not owned by the treasury", async () => {
+      await expectTX(
+        await sdk.withdrawAuthorFees({
+          bankKey: bank,
+          amount: new TokenAmount(Token.fromMint(mints[0], 6), 1_000),
+          recipient: Keypair.generate().publicKey,
+        }),
+        "withdraw to another owner"
+      ).to.be.rejected;
+    });
+
+    it("cannot apply a pending treasury before its timelock", async () => {
+      const wallet = sdk.provider.wallet.publicKey;
+      await bankman.methods
+        .setPendingTreasury()
+        .accounts({
+          bank,
+          bankman: wallet,
+          nextTreasury: Keypair.generate().publicKey,
+        })
+        .rpc();
+      await expect(
+        bankman.methods
+          .applyPendingTreasury()
+          .accounts({ bank, bankman: wallet })
+          .rpc(),
+        "apply before timelock"
+      ).to.be.rejected;
+
+      const { treasury } = (await bankman.account.bank.fetch(bank)) as {
+        treasury: PublicKey;
+      };
+      expect(treasury, "treasury unchanged").to.eqAddress(wallet);
+    });
+  });
+
+  describe("redemption queue", () => {
+    const brrr = workspace.Brrr as Program;
+
+    const newQueue = async (
+      kind:
+        | { saberLpArrow: Record<string, never> }
+        | { stablecoin: Record<string, never> }
+    ) => {
+      const { provider } = sdk;
+      const mintKP = Keypair.generate();
+      const mint = mintKP.publicKey;
+      await expectTX(
+        await createInitMintInstructions({ provider, mintKP, decimals: 6 }),
+        "create collateral mint"
+      ).to.be.fulfilled;
+      const { tx, collateralKey: collateral } = await sdk.authorizeCollateral({
+        bankKey: bank,
+        mint,
+        kind,
+      });
+      await expectTX(tx, "authorize collateral").to.be.fulfilled;
+
+      const [queue] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("RedemptionQueue"), collateral.toBuffer()],
+        brrr.programId
+      );
+      const escrow = await getOrCreateATA({
+        provider,
+        mint: cashToken.mintAccount,
+        owner: queue,
+      });
+      if (escrow.instruction) {
+        await expectTX(provider.newTX([escrow.instruction]), "create escrow")
+          .to.be.fulfilled;
+      }
+      const create = () =>
+        brrr.methods
+          .newRedemptionQueue()
+          .accounts({
+            bank,
+            collateral,
+            queue,
+            escrow: escrow.address,
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+      return { mint, collateral, queue, escrow: escrow.address, create };
+    };
+
+    it("creates queues for saber LP collateral", async () => {
+      const { create } = await newQueue({ saberLpArrow: {} });
+      await expect(create(), "saber LP queue").to.be.fulfilled;
+    });
+
+    it("cannot enqueue an empty redemption", async () => {
+      const { provider } = sdk;
+      const owner = provider.wallet.publicKey;
+      const { mint, collateral, queue, escrow, create } = await newQueue({
+        stablecoin: {},
+      });
+      await create();
+
+      const cashSource = await getOrCreateATA({
+        provider,
+        mint: cashToken.mintAccount,
+        owner,
+      });
+      const withdrawDestination = await getOrCreateATA({
+        provider,
+        mint,
+        owner,
+      });
+      await expectTX(
+        provider.newTX([
+          cashSource.instruction,
+          withdrawDestination.instruction,
+        ]),
+        "create token accounts"
+      ).to.be.fulfilled;
+
+      const [ticket] = await PublicKey.findProgramAddress(
+        [
+          utils.bytes.utf8.encode("RedemptionTicket"),
+          queue.toBuffer(),
+          new u64(0).toArrayLike(Buffer, "le", 8),
+        ],
+        brrr.programId
+      );
+      await expect(
+        brrr.methods
+          .enqueueRedemption(new BN(0))
+          .accounts({
+            queue,
+            ticket,
+            owner,
+            cashSource: cashSource.address,
+            escrow,
+            collateral,
+            withdrawDestination: withdrawDestination.address,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc(),
+        "empty redemption"
+      ).to.be.rejected;
+
+      const { tail } = (await brrr.account.redemptionQueue.fetch(queue)) as {
+        tail: BN;
+      };
+      expect(tail, "no tickets").to.bignumber.eq("0");
+    });
+  });
+
+  describe("roles", () => {
+    const bankman = workspace.Bankman as Program;
+
+    const fetchBank = async () =>
+      (await bankman.account.bank.fetch(bank)) as {
+        guardian: PublicKey;
+        riskManager: PublicKey;
+        paused: boolean;
+      };
+
+    it("guardian may pause but not unpause", async () => {
+      const wallet = sdk.provider.wallet.publicKey;
+      const guardianKP = Keypair.generate();
+      const guardian = guardianKP.publicKey;
+      await bankman.methods
+        .setGuardian()
+        .accounts({ bank, bankman: wallet, next: guardian })
+        .rpc();
+      await bankman.methods
+        .acceptGuardian()
+        .accounts({ bank, pending: guardian })
+        .signers([guardianKP])
+        .rpc();
+      expect((await fetchBank()).guardian, "guardian").to.eqAddress(guardian);
+
+      await bankman.methods
+        .pause()
+        .accounts({ bank, authority: guardian })
+        .signers([guardianKP])
+        .rpc();
+      expect((await fetchBank()).paused, "paused").to.be.true;
+
+      await expect(
+        bankman.methods
+          .unpause()
+          .accounts({ bank, bankman: guardian })
+          .signers([guardianKP])
+          .rpc(),
+        "unpause by guardian"
+      ).to.be.rejected;
+      await bankman.methods.unpause().accounts({ bank, bankman: wallet }).rpc();
+      expect((await fetchBank()).paused, "unpaused").to.be.false;
+    });
+
+    it("only the pending holder may accept a role", async () => {
+      const wallet = sdk.provider.wallet.publicKey;
+      const riskManagerKP = Keypair.generate();
+      const otherKP = Keypair.generate();
+      await bankman.methods
+        .setRiskManager()
+        .accounts({ bank, bankman: wallet, next: riskManagerKP.publicKey })
+        .rpc();
+
+      await expect(
+        bankman.methods
+          .acceptRiskManager()
+          .accounts({ bank, pending: otherKP.publicKey })
+          .signers([otherKP])
+          .rpc(),
+        "accept by another key"
+      ).to.be.rejected;
+      expect((await fetchBank()).riskManager, "unchanged").to.eqAddress(wallet);
+
+      await bankman.methods
+        .acceptRiskManager()
+        .accounts({ bank, pending: riskManagerKP.publicKey })
+        .signers([riskManagerKP])
+        .rpc();
+      expect((await fetchBank()).riskManager, "accepted").to.eqAddress(
+        riskManagerKP.publicKey
+      );
+    });
+  });
+
+  describe("governance", () => {
+    const bankman = workspace.Bankman as Program;
+    const mockGovernor = workspace.MockGovernor as Program;
+    let governor: PublicKey;
+    let smartWallet: PublicKey;
+
+    const fetchBankman = async (): Promise<PublicKey> =>
+      ((await bankman.account.bank.fetch(bank)) as { bankman: PublicKey })
+        .bankman;
+
+    beforeEach("create governor", async () => {
+      const baseKP = Keypair.generate();
+      [governor] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("Governor"), baseKP.publicKey.toBuffer()],
+        mockGovernor.programId
+      );
+      [smartWallet] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("SmartWallet"), governor.toBuffer()],
+        mockGovernor.programId
+      );
+      await mockGovernor.methods
+        .createGovernor(sdk.provider.wallet.publicKey, new BN(0))
+        .accounts({
+          base: baseKP.publicKey,
+          governor,
+          payer: sdk.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([baseKP])
+        .rpc();
+    });
+
+    it("rejects a smart wallet not recorded in the governor", async () => {
+      await expect(
+        bankman.methods
+          .setGovernor()
+          .accounts({
+            bank,
+            bankman: sdk.provider.wallet.publicKey,
+            governor,
+            smartWallet: Keypair.generate().publicKey,
+          })
+          .rpc(),
+        "wrong smart wallet"
+      ).to.be.rejected;
+    });
+
+    it("rejects a governor not owned by the govern program", async () => {
+      await expect(
+        bankman.methods
+          .setGovernor()
+          .accounts({
+            bank,
+            bankman: sdk.provider.wallet.publicKey,
+            governor: sdk.provider.wallet.publicKey,
+            smartWallet,
+          })
+          .rpc(),
+        "untrusted governor"
+      ).to.be.rejected;
+    });
+
+    it("only accepts bankman instructions from proposals", async () => {
+      const wallet = sdk.provider.wallet.publicKey;
+      await bankman.methods
+        .setGovernor()
+        .accounts({ bank, bankman: wallet, governor, smartWallet })
+        .rpc();
+      expect(await fetchBankman(), "governed").to.eqAddress(smartWallet);
+
+      await expect(
+        bankman.methods
+          .setBankman()
+          .accounts({ bank, bankman: wallet, nextBankman: wallet })
+          .rpc(),
+        "signed by the previous bankman"
+      ).to.be.rejected;
+
+      const ix = await bankman.methods
+        .setBankman()
+        .accounts({ bank, bankman: smartWallet, nextBankman: wallet })
+        .instruction();
+      const [proposal] = await PublicKey.findProgramAddress(
+        [
+          utils.bytes.utf8.encode("Proposal"),
+          governor.toBuffer(),
+          new u64(0).toArrayLike(Buffer, "le", 8),
+        ],
+        mockGovernor.programId
+      );
+      await mockGovernor.methods
+        .createProposal([
+          { programId: ix.programId, keys: ix.keys, data: ix.data },
+        ])
+        .accounts({
+          governor,
+          proposal,
+          proposer: wallet,
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await mockGovernor.methods
+        .approveProposal()
+        .accounts({ governor, proposal, electorate: wallet })
+        .rpc();
+      await mockGovernor.methods
+        .executeProposal()
+        .accounts({ governor, proposal })
+        .remainingAccounts([
+          ...ix.keys.map((key) => ({ ...key, isSigner: false })),
+          { pubkey: bankman.programId, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+      expect(await fetchBankman(), "returned").to.eqAddress(wallet);
+    });
+  });
+
+  describe("signer sets", () => {
+    const bankman = workspace.Bankman as Program;
+
+    it("may cover a shortfall from the insurance fund via a proposal", async () => {
+      const { provider } = sdk;
+      const wallet = provider.wallet.publicKey;
+
+      const mintKP = Keypair.generate();
+      const mint = mintKP.publicKey;
+      await expectTX(
+        await createInitMintInstructions({ provider, mintKP, decimals: 6 }),
+        "create collateral mint"
+      ).to.be.fulfilled;
+      const { tx, collateralKey: collateral } = await sdk.authorizeCollateral({
+        bankKey: bank,
+        mint,
+        kind: { stablecoin: {} },
+      });
+      await expectTX(tx, "authorize collateral").to.be.fulfilled;
+
+      const [insuranceFund] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("InsuranceFund"), bank.toBuffer()],
+        bankman.programId
+      );
+      await bankman.methods
+        .newInsuranceFund()
+        .accounts({
+          bank,
+          insuranceFund,
+          bankman: wallet,
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      const insuranceFundTokens = await getOrCreateATA({
+        provider,
+        mint,
+        owner: insuranceFund,
+      });
+      await expectTX(
+        provider.newTX([
+          insuranceFundTokens.instruction,
+          SPLToken.createMintToInstruction(
+            TOKEN_PROGRAM_ID,
+            mint,
+            insuranceFundTokens.address,
+            wallet,
+            [],
+            1_000
+          ),
+        ]),
+        "fund the insurance fund"
+      ).to.be.fulfilled;
+
+      const baseKP = Keypair.generate();
+      const [signerSet] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("SignerSet"), baseKP.publicKey.toBuffer()],
+        bankman.programId
+      );
+      await bankman.methods
+        .newSignerSet(1, new BN(1), [wallet])
+        .accounts({
+          base: baseKP.publicKey,
+          signerSet,
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([baseKP])
+        .rpc();
+      await bankman.methods
+        .setBankman()
+        .accounts({ bank, bankman: wallet, nextBankman: signerSet })
+        .rpc();
+
+      const { crateToken } = (await bankman.account.bank.fetch(bank)) as {
+        crateToken: PublicKey;
+      };
+      const crateCollateralTokens = await getATAAddress({
+        mint,
+        owner: crateToken,
+      });
+      const ix = await bankman.methods
+        .coverShortfall(new BN(400))
+        .accounts({
+          bank,
+          bankman: signerSet,
+          insuranceFund,
+          collateral,
+          insuranceFundTokens: insuranceFundTokens.address,
+          crateCollateralTokens,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .instruction();
+      const [proposal] = await PublicKey.findProgramAddress(
+        [
+          utils.bytes.utf8.encode("Proposal"),
+          signerSet.toBuffer(),
+          new u64(0).toArrayLike(Buffer, "le", 8),
+        ],
+        bankman.programId
+      );
+      await bankman.methods
+        .createProposal({ accounts: ix.keys, data: ix.data })
+        .accounts({
+          signerSet,
+          proposal,
+          proposer: wallet,
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await bankman.methods
+        .executeProposal()
+        .accounts({ signerSet, proposal, executor: wallet })
+        .remainingAccounts([
+          ...ix.keys.map((key) => ({ ...key, isSigner: false })),
+          { pubkey: bankman.programId, isSigner: false, isWritable: false },
+        ])
+        .rpc();
+
+      const backing = await getTokenAccount(provider, crateCollateralTokens);
+      expect(backing.amount.toNumber(), "backing").to.equal(400);
+      const fund = await getTokenAccount(provider, insuranceFundTokens.address);
+      expect(fund.amount.toNumber(), "insurance fund").to.equal(600);
+    });
+  });
+
+  describe("savings", () => {
+    const savings = workspace.Savings as Program;
+    let vault: PublicKey;
+    let shareMint: PublicKey;
+
+    const createATA = async (
+      mint: PublicKey,
+      owner: PublicKey
+    ): Promise<PublicKey> => {
+      const { address, instruction } = await getOrCreateATA({
+        provider: sdk.provider,
+        mint,
+        owner,
+      });
+      if (instruction) {
+        await expectTX(sdk.provider.newTX([instruction]), "create ATA").to.be
+          .fulfilled;
+      }
+      return address;
+    };
+
+    beforeEach("create savings vault", async () => {
+      [vault] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("SavingsVault"), bank.toBuffer()],
+        savings.programId
+      );
+
+      const shareMintKP = Keypair.generate();
+      shareMint = shareMintKP.publicKey;
+      await expectTX(
+        await createInitMintInstructions({
+          provider: sdk.provider,
+          mintKP: shareMintKP,
+          decimals: 6,
+          mintAuthority: vault,
+        }),
+        "create share mint"
+      ).to.be.fulfilled;
+
+      const cashTokens = await createATA(cashToken.mintAccount, vault);
+
+      await savings.methods
+        .newSavingsVault()
+        .accounts({
+          bank,
+          bankman: sdk.provider.wallet.publicKey,
+          vault,
+          shareMint,
+          cashTokens,
+          payer: sdk.provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+      await savings.methods
+        .setCooldownSeconds(new BN(100))
+        .accounts({
+          bank,
+          bankman: sdk.provider.wallet.publicKey,
+          vault,
+        })
+        .rpc();
+    });
+
+    it("cannot start a cooldown for shares not held", async () => {
+      const owner = sdk.provider.wallet.publicKey;
+      const shareSource = await createATA(shareMint, owner);
+
+      const [cooldown] = await PublicKey.findProgramAddress(
+        [
+          utils.bytes.utf8.encode("Cooldown"),
+          vault.toBuffer(),
+          owner.toBuffer(),
+        ],
+        savings.programId
+      );
+      const [shareEscrow] = await PublicKey.findProgramAddress(
+        [utils.bytes.utf8.encode("CooldownShares"), cooldown.toBuffer()],
+        savings.programId
+      );
+
+      await expect(
+        savings.methods
+          .startCooldown(new BN(1_000))
+          .accounts({
+            vault,
+            cooldown,
+            shareEscrow,
+            shareMint,
+            owner,
+            shareSource,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+          })
+          .rpc(),
+        "start cooldown without shares"
+      ).to.be.rejected;
+      expect(
+        await sdk.provider.connection.getAccountInfo(cooldown),
+        "no cooldown"
+      ).to.be.null;
+    });
+
+    it("cannot withdraw without a cooldown", async () => {
+      const owner = sdk.provider.wallet.publicKey;
+      const shareSource = await createATA(shareMint, owner);
+      const cashDestination = await createATA(cashToken.mintAccount, owner);
+      const cashTokens = await getATAAddress({
+        mint: cashToken.mintAccount,
+        owner: vault,
+      });
+
+      await expect(
+        savings.methods
+          .withdraw(new BN(0))
+          .accounts({
+            vault,
+            shareMint,
+            cashTokens,
+            owner,
+            shareSource,
+            cashDestination,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc(),
+        "withdraw with cooldown set"
+      ).to.be.rejected;
+    });
+  });
 });